@@ -0,0 +1,245 @@
+#![no_std]
+// Talep pozisyonlarını temsil eden, SEP-41 uyumlu makbuz (receipt) token'ı.
+//
+// Zaman kilitli kontrat, bir bakiyenin talep hakkı token'laştırıldığında bu kontratın bir
+// örneğini deploy eder ve kendisini yönetici olarak atar. Yönetici, bakiyedeki her token için
+// bir makbuz basar. Makbuzlar standart token arayüzüyle serbestçe transfer edilebilir; bakiye
+// açıldığında sahipleri makbuzları yakarak karşılığındaki token'ları zaman kilitli kontrattan alır.
+use soroban_sdk::token::TokenInterface;
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String};
+
+// Kalıcı kayıtların ömrü, zaman kilitli kontrattaki bakiyelerle aynıdır.
+const DAY_IN_LEDGERS: u32 = 17280;
+const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceValue {
+    pub amount: i128,
+    // Bu defterden (ledger) sonra izin geçersiz olur.
+    pub expiration_ledger: u32,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    // Makbuz basabilen zaman kilitli kontrat.
+    Admin,
+    Decimals,
+    Name,
+    Symbol,
+    // Adresin makbuz bakiyesi.
+    Balance(Address),
+    // Harcama izni; geçici (temporary) depolamada tutulur.
+    Allowance(AllowanceKey),
+}
+
+fn read_balance(env: &Env, id: &Address) -> i128 {
+    let key = DataKey::Balance(id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(balance) => {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+            balance
+        }
+        None => 0,
+    }
+}
+
+fn write_balance(env: &Env, id: &Address, amount: i128) {
+    let key = DataKey::Balance(id.clone());
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+fn check_amount(amount: i128) {
+    if amount < 0 {
+        panic!("negative amount is not allowed"); // "negatif miktara izin verilmez"
+    }
+}
+
+fn spend_balance(env: &Env, id: &Address, amount: i128) {
+    let balance = read_balance(env, id);
+    if balance < amount {
+        panic!("insufficient balance"); // "yetersiz bakiye"
+    }
+    write_balance(env, id, balance - amount);
+}
+
+fn receive_balance(env: &Env, id: &Address, amount: i128) {
+    let balance = read_balance(env, id);
+    write_balance(env, id, balance + amount);
+}
+
+fn read_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = DataKey::Allowance(AllowanceKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    match env.storage().temporary().get::<_, AllowanceValue>(&key) {
+        Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+        _ => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+fn write_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        panic!("expiration_ledger is less than ledger seq when amount > 0"); // "izin süresi geçmişte"
+    }
+    let key = DataKey::Allowance(AllowanceKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    env.storage().temporary().set(
+        &key,
+        &AllowanceValue {
+            amount,
+            expiration_ledger,
+        },
+    );
+    if amount > 0 {
+        let live_for = expiration_ledger - env.ledger().sequence();
+        env.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
+
+fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = read_allowance(env, from, spender);
+    if allowance.amount < amount {
+        panic!("insufficient allowance"); // "yetersiz harcama izni"
+    }
+    if amount > 0 {
+        write_allowance(
+            env,
+            from,
+            spender,
+            allowance.amount - amount,
+            allowance.expiration_ledger,
+        );
+    }
+}
+
+fn burn_balance(env: &Env, from: &Address, amount: i128) {
+    spend_balance(env, from, amount);
+    env.events()
+        .publish((symbol_short!("burn"), from.clone()), amount);
+}
+
+fn move_balance(env: &Env, from: &Address, to: &Address, amount: i128) {
+    spend_balance(env, from, amount);
+    receive_balance(env, to, amount);
+    env.events().publish(
+        (symbol_short!("transfer"), from.clone(), to.clone()),
+        amount,
+    );
+}
+
+#[contract]
+pub struct ReceiptToken;
+
+#[contractimpl]
+impl ReceiptToken {
+    // `admin`: Makbuz basabilen zaman kilitli kontrat. `decimal` karşılık gelen token'ınkiyle aynıdır.
+    pub fn __constructor(env: Env, admin: Address, decimal: u32, name: String, symbol: String) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Decimals, &decimal);
+        env.storage().instance().set(&DataKey::Name, &name);
+        env.storage().instance().set(&DataKey::Symbol, &symbol);
+    }
+
+    // `to`'ya `amount` kadar makbuz basar. Sadece yönetici çağırabilir.
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        check_amount(amount);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        receive_balance(&env, &to, amount);
+        env.events()
+            .publish((symbol_short!("mint"), admin, to), amount);
+    }
+
+    // Makbuz basabilen zaman kilitli kontratı döndürür.
+    pub fn admin(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for ReceiptToken {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        read_allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        check_amount(amount);
+        write_allowance(&env, &from, &spender, amount, expiration_ledger);
+        env.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (amount, expiration_ledger),
+        );
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        read_balance(&env, &id)
+    }
+
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        check_amount(amount);
+        move_balance(&env, &from, &to, amount);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        check_amount(amount);
+        spend_allowance(&env, &from, &spender, amount);
+        move_balance(&env, &from, &to, amount);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        check_amount(amount);
+        burn_balance(&env, &from, amount);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        check_amount(amount);
+        spend_allowance(&env, &from, &spender, amount);
+        burn_balance(&env, &from, amount);
+    }
+
+    fn decimals(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Decimals).unwrap()
+    }
+
+    fn name(env: Env) -> String {
+        env.storage().instance().get(&DataKey::Name).unwrap()
+    }
+
+    fn symbol(env: Env) -> String {
+        env.storage().instance().get(&DataKey::Symbol).unwrap()
+    }
+}
+
+mod test;
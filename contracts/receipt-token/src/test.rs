@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::TokenClient;
+
+// Yöneticisi `admin` olan bir makbuz token'ı deploy eder.
+fn create_receipt<'a>(env: &Env, admin: &Address) -> (ReceiptTokenClient<'a>, TokenClient<'a>) {
+    let address = env.register(
+        ReceiptToken,
+        (
+            admin.clone(),
+            7_u32,
+            String::from_str(env, "Timelock Receipt"),
+            String::from_str(env, "RCPT"),
+        ),
+    );
+    (
+        ReceiptTokenClient::new(env, &address),
+        TokenClient::new(env, &address),
+    )
+}
+
+// Bu test, basılan makbuzların transfer edilip yakılabildiğini kontrol eder.
+#[test]
+fn test_mint_transfer_burn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let (holder, buyer) = (Address::generate(&env), Address::generate(&env));
+    let (receipt, token) = create_receipt(&env, &admin);
+
+    receipt.mint(&holder, &1000);
+    token.transfer(&holder, &buyer, &400);
+    token.burn(&buyer, &100);
+    assert_eq!(token.balance(&holder), 600);
+    assert_eq!(token.balance(&buyer), 300);
+    assert_eq!(token.decimals(), 7);
+    assert_eq!(token.symbol(), String::from_str(&env, "RCPT"));
+    assert_eq!(receipt.admin(), admin);
+    assert!(token.try_transfer(&buyer, &holder, &301).is_err());
+}
+
+// Bu test, harcama izniyle transferin izin miktarı ve süresiyle sınırlı olduğunu kontrol eder.
+#[test]
+fn test_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let (holder, spender) = (Address::generate(&env), Address::generate(&env));
+    let (receipt, token) = create_receipt(&env, &admin);
+    receipt.mint(&holder, &1000);
+
+    token.approve(&holder, &spender, &500, &100);
+    token.transfer_from(&spender, &holder, &spender, &300);
+    assert_eq!(token.allowance(&holder, &spender), 200);
+    assert!(token
+        .try_transfer_from(&spender, &holder, &spender, &201)
+        .is_err());
+    token.burn_from(&spender, &holder, &200);
+    assert_eq!(token.balance(&holder), 500);
+
+    token.approve(&holder, &spender, &500, &100);
+    env.ledger().set_sequence_number(101);
+    assert_eq!(token.allowance(&holder, &spender), 0);
+}
@@ -0,0 +1,25 @@
+// Yönetici (admin) adresinin saklanması ve doğrulanması için yardımcı fonksiyonlar.
+// Yönetici, kontrat deploy edilirken kurucu (constructor) fonksiyon ile belirlenir.
+use soroban_sdk::{Address, Env};
+
+use crate::DataKey;
+
+// Yönetici adresini kontratın örnek (instance) depolamasına yazar.
+pub(crate) fn write_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+// Kayıtlı yönetici adresini okur. Yönetici hiç atanmamışsa paniklet.
+pub(crate) fn read_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic!("admin is not set")) // "yönetici atanmamış"
+}
+
+// Kayıtlı yöneticinin bu çağrıyı yetkilendirdiğinden emin olur ve yönetici adresini döndürür.
+pub(crate) fn require_admin(env: &Env) -> Address {
+    let admin = read_admin(env);
+    admin.require_auth();
+    admin
+}
@@ -0,0 +1,72 @@
+// Harcama izni (allowance) üzerinden yatırma.
+//
+// Yatıran, token kontratında bu kontrata önceden bir bütçe onaylar (`approve`) ve yatırmaları
+// kendi adına yapabilecek operatörleri (örn. bir aktarıcı/relayer veya arka uç servisi)
+// belirler. Operatör yatırmayı gönderir; token'lar `transfer_from` ile çekildiği için
+// yatıranın her işlemi imzalaması gerekmez.
+use soroban_sdk::{contractimpl, panic_with_error, Address, Env, Vec};
+
+use crate::{
+    check_deposit, create_balance, receive_tokens, registry, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, Error, TimeBound,
+    BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+// `operator`'ün `from` adına yatırma yapmaya yetkili olup olmadığını okur.
+fn is_operator(env: &Env, from: &Address, operator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Operator(from.clone(), operator.clone()))
+        .unwrap_or(false)
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `operator`'e, `from`'un kontrata verdiği harcama izninden yatırma yapma yetkisi verir
+    // (`allowed = true`) veya bu yetkiyi geri alır. `from` yetkilendirmelidir.
+    pub fn set_operator(env: Env, from: Address, operator: Address, allowed: bool) {
+        from.require_auth();
+        let key = DataKey::Operator(from, operator);
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    // `operator`'ün `from` adına yatırma yapmaya yetkili olup olmadığını döndürür.
+    pub fn is_operator(env: Env, from: Address, operator: Address) -> bool {
+        is_operator(&env, &from, &operator)
+    }
+
+    // `deposit` ile aynı şekilde yeni bir bakiye oluşturur ve kimliğini döndürür, ancak token'ları `from`'un bu kontrata verdiği
+    // harcama izninden `transfer_from` ile çeker. Çağrıyı `from` değil, `from`'un yetkilendirdiği
+    // `operator` imzalar. Yatıran olarak `from` kaydedilir.
+    pub fn deposit_from_allowance(
+        env: Env,
+        operator: Address,
+        from: Address,
+        token: Address,
+        amount: i128,
+        claimants: Vec<Address>,
+        time_bound: TimeBound,
+    ) -> u64 {
+        check_deposit(&env, amount, &claimants);
+        operator.require_auth();
+        if !is_operator(&env, &from, &operator) {
+            panic_with_error!(&env, Error::OperatorNotApproved);
+        }
+        registry::ensure_allowed(&env, &from);
+
+        // Harcayan (spender) bu kontratın kendisidir; kontrat doğrudan çağıran olduğu için
+        // `transfer_from`'un istediği yetki kendiliğinden sağlanır.
+        let contract = env.current_contract_address();
+        let amount = receive_tokens(&env, &token, |client| {
+            client.transfer_from(&contract, &from, &contract, &amount)
+        });
+        create_balance(&env, from, token, amount, claimants, time_bound)
+    }
+}
@@ -0,0 +1,89 @@
+// Toplu yatırma: tek çağrıda, tek token transferiyle birden çok bakiye oluşturma.
+//
+// Token lansmanlarında yüzlerce yatırımcı kilidi oluşturulur; her biri için ayrı `deposit`
+// çağırmak yavaş ve pahalıdır. `batch_deposit` tüm kilitlerin toplamını bir kez çeker, kayıt
+// kontrolünü toplam için bir kez yapar ve her kilit için `deposit` ile aynı şekilde ayrı bir bakiye
+// oluşturur. Kilitlerden biri bile geçersizse hiçbir şey yapılmaz.
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env, Vec};
+
+use crate::{
+    check_deposit, create_balance_at, next_balance_id, receive_tokens, registry, voting,
+    ClaimableBalanceContract, ClaimableBalanceContractArgs, ClaimableBalanceContractClient, Error,
+    TimeBound,
+};
+
+// Bir toplu yatırmadaki en fazla kilit sayısı. Her kilit bir bakiye kaydı yazar, bir yatırma
+// olayı ve ücret tarifesi varsa bir ücret transferi olayı yayınlar; bu sınır, en kötü durumda da
+// işlemin yazma kaydı sayısı ve olay boyutu sınırlarının altında kalmasını sağlar.
+pub const MAX_BATCH_SIZE: u32 = 15;
+
+// Toplu yatırmadaki tek bir kilidin tanımı.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LockSpec {
+    // Kilitlenen token miktarı.
+    pub amount: i128,
+    // Bakiyeyi talep edebilecek adresler.
+    pub claimants: Vec<Address>,
+    // Bakiyenin ne zaman talep edilebileceği.
+    pub time_bound: TimeBound,
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `specs`'teki her kilit için `deposit` ile aynı şekilde bir bakiye oluşturur ve kimliklerini
+    // aynı sırayla döndürür. Toplam miktar `from`'dan tek bir transferle çekilir; her kilit
+    // kendi miktarının yatırma ücretini öder.
+    // Liste boşsa, `MAX_BATCH_SIZE`'ı aşıyorsa, bir kilit oy kilidiyse veya transferde toplamdan
+    // az token ulaşırsa `Error::InvalidBatch` ile, kilitlerden biri geçersizse o kilidin hatasıyla
+    // paniklet. Oy kilitleri kilit başına birkaç kayıt daha yazdığı için tek tek yatırılmalıdır.
+    pub fn batch_deposit(
+        env: Env,
+        from: Address,
+        token: Address,
+        specs: Vec<LockSpec>,
+    ) -> Vec<u64> {
+        if specs.is_empty() || specs.len() > MAX_BATCH_SIZE {
+            panic_with_error!(&env, Error::InvalidBatch);
+        }
+        // Token çekilmeden önce tüm kilitleri doğrula ve toplamı hesapla.
+        let mut total: i128 = 0;
+        for spec in specs.iter() {
+            check_deposit(&env, spec.amount, &spec.claimants);
+            if voting::is_voting_lock(&env, &from, &token, &spec.claimants, &spec.time_bound) {
+                panic_with_error!(&env, Error::InvalidBatch);
+            }
+            total = total
+                .checked_add(spec.amount)
+                .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidAmount));
+        }
+        from.require_auth();
+        registry::ensure_allowed(&env, &from);
+
+        let received = receive_tokens(&env, &token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &total)
+        });
+        // Transferde ücret kesen token'larda eksik ulaşan miktar kilitlere paylaştırılamaz.
+        if received != total {
+            panic_with_error!(&env, Error::InvalidBatch);
+        }
+
+        // Her kilit `deposit` gibi kendi miktarının ücretini öder; böylece net miktar hiçbir
+        // kilitte sıfıra veya altına inmez.
+        let mut balance_ids = Vec::new(&env);
+        for spec in specs {
+            let balance_id = next_balance_id(&env);
+            create_balance_at(
+                &env,
+                balance_id,
+                from.clone(),
+                token.clone(),
+                spec.amount,
+                spec.claimants,
+                spec.time_bound,
+            );
+            balance_ids.push_back(balance_id);
+        }
+        balance_ids
+    }
+}
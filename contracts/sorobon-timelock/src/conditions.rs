@@ -0,0 +1,245 @@
+// Zaman sınırına ek olarak talebi dış bir koşula bağlayan koşullu bakiyeler.
+//
+// Koşul, bakiyenin yanında ayrı bir anahtarda tutulur ve `claim` sırasında, zaman sınırı
+// ve talepçi kontrollerinden sonra değerlendirilir. Koşulsuz bakiyeler bundan etkilenmez.
+//
+// Sahiplik koşullu bakiyeler boş bir talepçi listesiyle de oluşturulabilir; bu durumda
+// koşulu sağlayan her adres bakiyeyi talep edebilir.
+//
+// Bağımlılık koşulunda döngü oluşamaz: bağlı olunan bakiye, bağımlı bakiye oluşturulurken
+// zaten var olmalıdır, yani her zaman daha küçük bir kimliğe sahiptir; koşullar da sonradan
+// değiştirilemez.
+use soroban_sdk::{
+    contractclient, contractimpl, contracttype, panic_with_error, token, Address, Env, Symbol, Vec,
+};
+
+use crate::{
+    check_deposit, create_balance, pause, read_balance, receive_tokens, registry,
+    ClaimableBalanceContract, ClaimableBalanceContractArgs, ClaimableBalanceContractClient,
+    DataKey, Error, TimeBound, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+// Fiyat kâhini (oracle) arayüzündeki varlık tanımı (SEP-40 ile uyumlu).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Asset {
+    // Stellar üzerindeki bir token kontratı.
+    Stellar(Address),
+    // Başka bir varlık (ör. `USD`).
+    Other(Symbol),
+}
+
+// Kâhinin bildirdiği fiyat ve fiyatın zaman damgası.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+// Fiyat kâhini kontratlarının uyması gereken arayüz.
+#[contractclient(name = "OracleClient")]
+pub trait Oracle {
+    // Varlığın en son fiyatını döndürür; fiyat yoksa `None`.
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Comparison {
+    // Fiyat eşikten kesin olarak büyük olmalı.
+    Above,
+    // Fiyat eşikten kesin olarak küçük olmalı.
+    Below,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PriceCondition {
+    // Fiyatın sorulacağı kâhin kontratı.
+    pub oracle: Address,
+    // Fiyatı sorulan varlık.
+    pub asset: Asset,
+    // Fiyatın eşikle nasıl karşılaştırılacağı.
+    pub comparison: Comparison,
+    // Eşik fiyat (kâhinin ondalık biçiminde).
+    pub threshold: i128,
+    // Fiyatın en fazla kaç saniye eski olabileceği; daha eski fiyatlar kabul edilmez.
+    pub max_age: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct HoldingCondition {
+    // Talepçinin elinde tutması gereken (ör. yönetişim) token'ın adresi.
+    pub token: Address,
+    // Talep anında talepçinin en az bu kadar tutması gerekir.
+    pub min_balance: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Condition {
+    // Kâhinin bildirdiği fiyat eşiğin üstünde/altında olmalı.
+    Price(PriceCondition),
+    // Verilen kimlikteki bakiye kapanmış olmalı: ya talep edilmiş ya da yatıranı tarafından
+    // geri alınmış (iptal edilmiş). İki durumda da bağımlı bakiye açılır.
+    Dependency(u64),
+    // Talepçi, talep anında belirtilen token'dan en az `min_balance` kadar tutmalı.
+    Holding(HoldingCondition),
+}
+
+// Bir bakiyenin nasıl kapandığı.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum BalanceOutcome {
+    // Bir talepçi bakiyeyi talep etti.
+    Claimed,
+    // Yatıran bakiyeyi geri aldı.
+    Reclaimed,
+}
+
+// Bağımlısı olan bir bakiye kapanırken sonucunu saklar. Bağımlısı olmayan bakiyeler için
+// kayıt tutulmaz.
+pub(crate) fn record_outcome(env: &Env, balance_id: u64, outcome: BalanceOutcome) {
+    let storage = env.storage().persistent();
+    if !storage.has(&DataKey::HasDependents(balance_id)) {
+        return;
+    }
+    storage.remove(&DataKey::HasDependents(balance_id));
+    let key = DataKey::Outcome(balance_id);
+    storage.set(&key, &outcome);
+    storage.extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+fn check_dependency(env: &Env, dependency_id: u64) {
+    let key = DataKey::Outcome(dependency_id);
+    if !env.storage().persistent().has(&key) {
+        panic_with_error!(env, Error::ConditionNotMet);
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+fn check_holding(env: &Env, condition: &HoldingCondition, claimant: &Address) {
+    if token::Client::new(env, &condition.token).balance(claimant) < condition.min_balance {
+        panic_with_error!(env, Error::ConditionNotMet);
+    }
+}
+
+// Yeni bir bakiyeye verilecek koşulu doğrular ve gerekiyorsa bağlı olunan bakiyeyi işaretler.
+fn prepare_condition(env: &Env, condition: &Condition) {
+    if let Condition::Holding(holding) = condition {
+        if holding.min_balance <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+    }
+    if let Condition::Dependency(dependency_id) = condition {
+        // Bakiye hâlâ açık olmalı. Zaten kapanmış bir bakiyenin sonucu sadece bağımlısı
+        // varsa saklandığı için, kapanmış bakiyelere yeni bağımlılık eklenemez.
+        if read_balance(env, *dependency_id).is_none() {
+            panic_with_error!(env, Error::InvalidDependency);
+        }
+        let key = DataKey::HasDependents(*dependency_id);
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+}
+
+fn check_price(env: &Env, condition: &PriceCondition) {
+    let price = OracleClient::new(env, &condition.oracle)
+        .lastprice(&condition.asset)
+        .unwrap_or_else(|| panic_with_error!(env, Error::StalePrice));
+    if env.ledger().timestamp().saturating_sub(price.timestamp) > condition.max_age {
+        panic_with_error!(env, Error::StalePrice);
+    }
+    let met = match condition.comparison {
+        Comparison::Above => price.price > condition.threshold,
+        Comparison::Below => price.price < condition.threshold,
+    };
+    if !met {
+        panic_with_error!(env, Error::ConditionNotMet);
+    }
+}
+
+// Bakiyenin bir sahiplik koşulu olup olmadığını döndürür. Talepçi listesi boş bakiyeleri
+// sadece bu koşul korur.
+pub(crate) fn has_holding_condition(env: &Env, balance_id: u64) -> bool {
+    matches!(
+        env.storage()
+            .persistent()
+            .get::<_, Condition>(&DataKey::Condition(balance_id)),
+        Some(Condition::Holding(_))
+    )
+}
+
+// Bakiyenin koşulu varsa `claimant` için değerlendirir; sağlanmıyorsa ilgili tipli hatayla paniklet.
+pub(crate) fn check_condition(env: &Env, balance_id: u64, claimant: &Address) {
+    let key = DataKey::Condition(balance_id);
+    let Some(condition) = env.storage().persistent().get::<_, Condition>(&key) else {
+        return;
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    match condition {
+        Condition::Price(condition) => check_price(env, &condition),
+        Condition::Dependency(dependency_id) => check_dependency(env, dependency_id),
+        Condition::Holding(condition) => check_holding(env, &condition, claimant),
+    }
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `deposit` gibi bir bakiye oluşturur; bakiye ayrıca `condition` sağlandığında talep edilebilir.
+    // Koşul sonradan değiştirilemez. Koşul bir sahiplik koşuluysa `claimants` boş bırakılabilir;
+    // bu durumda koşulu sağlayan her adres talep edebilir.
+    pub fn deposit_conditional(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        claimants: Vec<Address>,
+        time_bound: TimeBound,
+        condition: Condition,
+    ) -> u64 {
+        if claimants.is_empty() && matches!(condition, Condition::Holding(_)) {
+            pause::ensure_not_paused(&env);
+            if amount <= 0 {
+                panic_with_error!(&env, Error::InvalidAmount);
+            }
+        } else {
+            check_deposit(&env, amount, &claimants);
+        }
+        prepare_condition(&env, &condition);
+        from.require_auth();
+        registry::ensure_allowed(&env, &from);
+
+        let amount = receive_tokens(&env, &token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &amount)
+        });
+        let balance_id = create_balance(&env, from, token, amount, claimants, time_bound);
+        let key = DataKey::Condition(balance_id);
+        env.storage().persistent().set(&key, &condition);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        balance_id
+    }
+
+    // Bağımlısı olan kapanmış bir bakiyenin nasıl kapandığını döndürür; bakiye hâlâ açıksa
+    // veya hiç bağımlısı olmadıysa `None` döner.
+    pub fn get_outcome(env: Env, balance_id: u64) -> Option<BalanceOutcome> {
+        env.storage().persistent().get(&DataKey::Outcome(balance_id))
+    }
+
+    // Bakiyenin koşulunu döndürür; koşulsuz bakiyelerde `None` döner.
+    pub fn get_condition(env: Env, balance_id: u64) -> Option<Condition> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Condition(balance_id))
+    }
+}
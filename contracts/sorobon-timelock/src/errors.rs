@@ -0,0 +1,70 @@
+// Kontratın döndürdüğü tipli hatalar.
+// İstemciler bu kodlara göre hatanın nedenini ayırt edebilir (`try_` fonksiyonları `Error` döndürür).
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    // Yatırılan miktar sıfır veya negatif.
+    InvalidAmount = 1,
+    // Talepçi listesi boş; fonlar kalıcı olarak kilitli kalırdı.
+    NoClaimants = 2,
+    // Talepçi listesi yapılandırılmış en yüksek sayıdan uzun.
+    TooManyClaimants = 3,
+    // Talepçi listesinde aynı adres birden fazla kez geçiyor.
+    DuplicateClaimant = 4,
+    // Yönetici tarafından verilen en yüksek talepçi sayısı geçersiz.
+    InvalidMaxClaimants = 5,
+    // Transferden sonra kontratın token bakiyesi artmadı.
+    NothingReceived = 6,
+    // Operatör, yatıran adına harcama izninden yatırma yapmaya yetkili değil.
+    OperatorNotApproved = 7,
+    // Verilen kimlikte bir bakiye yok (hiç oluşturulmamış, talep edilmiş veya geri alınmış).
+    BalanceNotFound = 8,
+    // Bakiyenin kuralları bu adresin bakiyeye token eklemesine izin vermiyor.
+    TopUpNotAllowed = 9,
+    // Merkle ispatı yaprağı dağıtımın köküne bağlamıyor.
+    InvalidProof = 10,
+    // Bu yaprak daha önce talep edildi.
+    AlreadyClaimed = 11,
+    // Talep edilen miktar, kalan bakiyeden fazla.
+    AmountExceedsBalance = 12,
+    // Bu numaralı kupon daha önce kullanıldı.
+    NonceUsed = 13,
+    // Zaman planı geçersiz (başlangıç bitişten önce değil veya bitiş geçmişte).
+    InvalidSchedule = 14,
+    // Son talepten bu yana talep edilebilecek yeni bir ödeme açılmadı.
+    NothingToClaim = 15,
+    // Bakiye bir miras kilidi değil.
+    NotInheritanceLock = 16,
+    // Talepçinin kademesi şu anda talep edemez (önceki kademenin süresi dolmadı veya kendi süresi doldu).
+    TierNotActive = 17,
+    // Emanet bu işlem için gereken durumda değil.
+    InvalidEscrowState = 18,
+    // Aşama yok veya bu işlem için gereken durumda değil.
+    InvalidMilestone = 19,
+    // Bakiyenin talep koşulu sağlanmıyor.
+    ConditionNotMet = 20,
+    // Kâhin fiyat bildirmedi veya bildirdiği fiyat izin verilenden eski.
+    StalePrice = 21,
+    // Bağlı olunan bakiye yok: hiç oluşturulmamış veya zaten kapanmış.
+    InvalidDependency = 22,
+    // Kayıt kontratı adrese fon yatırma veya talep etme izni vermiyor.
+    NotAllowed = 23,
+    // Erken talep kapalı veya bu bakiye için kullanılamaz ('After' türünde değil ya da miras kilidi).
+    EarlyClaimNotAvailable = 24,
+    // Bakiyenin kilidi uzatılamaz ('After' türünde değil ya da miras kilidi) veya çağıran uzatmaya yetkili değil.
+    ExtensionNotAllowed = 25,
+    // Bakiyenin talep hakkı makbuz token'larına dönüştürüldü; bakiye sadece `redeem` ile talep edilebilir.
+    BalanceTokenized = 26,
+    // Bakiye token'laştırılamaz (kademeli, koşullu veya miras kilidi ya da makbuz Wasm'ı ayarlanmamış)
+    // veya token'laştırılmamış.
+    NotTokenizable = 27,
+    // Toplu yatırma boş, `MAX_BATCH_SIZE`'tan büyük veya kontrata toplamdan az token ulaştı.
+    InvalidBatch = 28,
+    // Zaman sınırı bu işlemde desteklenmiyor (örn. Merkle dağıtımında 'Before' dışında bir tür).
+    InvalidTimeBound = 29,
+    // Emanetin hakemi alıcı veya satıcının kendisi.
+    InvalidArbiter = 30,
+}
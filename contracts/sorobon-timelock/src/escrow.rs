@@ -0,0 +1,282 @@
+// Hakemli iki taraflı emanet (escrow).
+//
+// Alıcı token'ları yatırır, satıcı lehtardır. Alıcı teslimi onaylarsa ya da zaman aşımı
+// dolana kadar bir anlaşmazlık açılmazsa token'lar satıcıya ödenir. Anlaşmazlık açılırsa
+// önceden belirlenen hakem, miktarın alıcı ve satıcı arasında nasıl bölüneceğine karar verir.
+// Hakem `ARBITRATION_PERIOD` içinde karar vermezse alıcı token'ların tamamını geri alabilir.
+//
+// Durumlar:
+//   Funded ──onay / zaman aşımı──▶ Released
+//     │ └──────satıcı iadesi─────▶ Refunded
+//     └─anlaşmazlık─▶ Disputed ──hakem──▶ Resolved (veya tamamı alıcıya ise Refunded)
+//                        └──karar süresi doldu──▶ Refunded
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, token, Address, Env};
+
+use crate::{
+    events, fee, next_balance_id, pause, receive_tokens, registry, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, Error,
+    BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+// Anlaşmazlık açıldıktan sonra hakemin karar vermek için sahip olduğu süre (saniye).
+pub const ARBITRATION_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EscrowState {
+    // Token'lar yatırıldı; onay, zaman aşımı veya anlaşmazlık bekleniyor.
+    Funded,
+    // Token'lar satıcıya ödendi.
+    Released,
+    // Taraflardan biri anlaşmazlık açtı; hakemin kararı bekleniyor.
+    Disputed,
+    // Hakem token'ları alıcı ve satıcı arasında böldü.
+    Resolved,
+    // Token'ların tamamı alıcıya iade edildi.
+    Refunded,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Escrow {
+    // Token'ları yatıran alıcı.
+    pub buyer: Address,
+    // Token'ların lehtarı olan satıcı.
+    pub seller: Address,
+    // Anlaşmazlıkta karar veren hakem.
+    pub arbiter: Address,
+    // Emanetteki token'ın adresi.
+    pub token: Address,
+    // Emanetteki (net) token miktarı.
+    pub amount: i128,
+    // Bu andan itibaren, anlaşmazlık yoksa satıcı token'ları alabilir.
+    pub release_after: u64,
+    // Anlaşmazlık açıldıysa hakemin karar verebileceği son an; sonrasında alıcı token'ları geri alabilir.
+    pub resolve_before: Option<u64>,
+    // Emanetin durumu.
+    pub state: EscrowState,
+}
+
+fn read_escrow(env: &Env, escrow_id: u64) -> Escrow {
+    let key = DataKey::Escrow(escrow_id);
+    let escrow = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, Error::BalanceNotFound));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    escrow
+}
+
+fn write_escrow(env: &Env, escrow_id: u64, escrow: &Escrow) {
+    let key = DataKey::Escrow(escrow_id);
+    env.storage().persistent().set(&key, escrow);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// Emanetin beklenen durumda olduğunu kontrol eder; değilse `Error::InvalidEscrowState` ile paniklet.
+fn expect_state(env: &Env, escrow: &Escrow, allowed: &[EscrowState]) {
+    if !allowed.contains(&escrow.state) {
+        panic_with_error!(env, Error::InvalidEscrowState);
+    }
+}
+
+// Emaneti `state` durumuna geçirir; satıcıya `to_seller` (talep ücreti kesilerek),
+// alıcıya `to_buyer` (ücretsiz) öder ve olayı yayınlar.
+fn settle(
+    env: &Env,
+    escrow_id: u64,
+    mut escrow: Escrow,
+    actor: &Address,
+    state: EscrowState,
+    to_seller: i128,
+) {
+    let to_buyer = escrow.amount - to_seller;
+    // Token alacak taraflar, herhangi bir transferden önce kontrol edilir.
+    if to_seller > 0 {
+        registry::ensure_allowed(env, &escrow.seller);
+    }
+    if to_buyer > 0 {
+        registry::ensure_allowed(env, &escrow.buyer);
+    }
+    escrow.state = state;
+    write_escrow(env, escrow_id, &escrow);
+
+    let client = token::Client::new(env, &escrow.token);
+    let contract = env.current_contract_address();
+    let (paid, fee) = if to_seller > 0 {
+        let (paid, fee) = fee::charge_claim_fee(env, &escrow.token, to_seller);
+        client.transfer(&contract, &escrow.seller, &paid);
+        (paid, fee)
+    } else {
+        (0, 0)
+    };
+    if to_buyer > 0 {
+        client.transfer(&contract, &escrow.buyer, &to_buyer);
+    }
+    events::escrow(env, actor, escrow_id, state, paid, to_buyer, fee);
+}
+
+// Anlaşmazlıktaki bir emanet için hakemin karar süresinin dolup dolmadığını kontrol eder.
+fn arbitration_expired(env: &Env, escrow: &Escrow) -> bool {
+    match escrow.resolve_before {
+        Some(resolve_before) => env.ledger().timestamp() >= resolve_before,
+        None => false,
+    }
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `buyer`'dan `amount` kadar token yatırarak `seller` lehine bir emanet oluşturur ve
+    // kimliğini döndürür. Anlaşmazlık açılmazsa satıcı `release_after` anından itibaren
+    // token'ları alabilir. Hakem taraflardan biri olamaz ve `release_after` gelecekte olmalıdır.
+    pub fn create_escrow(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        arbiter: Address,
+        token: Address,
+        amount: i128,
+        release_after: u64,
+    ) -> u64 {
+        pause::ensure_not_paused(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if arbiter == buyer || arbiter == seller {
+            panic_with_error!(&env, Error::InvalidArbiter);
+        }
+        // Geçmişteki bir zaman aşımı, alıcıya itiraz için hiç süre bırakmazdı.
+        if release_after <= env.ledger().timestamp() {
+            panic_with_error!(&env, Error::InvalidSchedule);
+        }
+        buyer.require_auth();
+        registry::ensure_allowed(&env, &buyer);
+
+        let received = receive_tokens(&env, &token, |client| {
+            client.transfer(&buyer, &env.current_contract_address(), &amount)
+        });
+        let escrow_id = next_balance_id(&env);
+        let (amount, fee) = fee::charge_deposit_fee(&env, &token, received);
+        events::deposit(&env, &buyer, escrow_id, &token, amount, fee);
+        write_escrow(
+            &env,
+            escrow_id,
+            &Escrow {
+                buyer,
+                seller,
+                arbiter,
+                token,
+                amount,
+                release_after,
+                resolve_before: None,
+                state: EscrowState::Funded,
+            },
+        );
+        escrow_id
+    }
+
+    // Alıcının teslimi onaylamasını sağlar; token'lar hemen satıcıya ödenir.
+    pub fn confirm_escrow(env: Env, escrow_id: u64) {
+        pause::ensure_not_paused(&env);
+        let escrow = read_escrow(&env, escrow_id);
+        expect_state(&env, &escrow, &[EscrowState::Funded]);
+        escrow.buyer.require_auth();
+        let (buyer, amount) = (escrow.buyer.clone(), escrow.amount);
+        settle(&env, escrow_id, escrow, &buyer, EscrowState::Released, amount);
+    }
+
+    // Zaman aşımı dolduktan sonra, anlaşmazlık açılmamışsa satıcının token'ları almasını sağlar.
+    pub fn release_escrow(env: Env, escrow_id: u64) {
+        pause::ensure_not_paused(&env);
+        let escrow = read_escrow(&env, escrow_id);
+        expect_state(&env, &escrow, &[EscrowState::Funded]);
+        escrow.seller.require_auth();
+        if env.ledger().timestamp() < escrow.release_after {
+            panic!("time predicate is not fulfilled"); // "zaman koşulu karşılanmadı"
+        }
+        let (seller, amount) = (escrow.seller.clone(), escrow.amount);
+        settle(&env, escrow_id, escrow, &seller, EscrowState::Released, amount);
+    }
+
+    // Alıcı veya satıcının, zaman aşımı dolmadan önce anlaşmazlık açmasını sağlar.
+    // Kontrat durdurulmuşken de çalışır; böylece durdurma sırasında dolan zaman aşımı
+    // alıcının itiraz hakkını ortadan kaldırmaz.
+    pub fn dispute_escrow(env: Env, escrow_id: u64, by: Address) {
+        let mut escrow = read_escrow(&env, escrow_id);
+        expect_state(&env, &escrow, &[EscrowState::Funded]);
+        if by != escrow.buyer && by != escrow.seller {
+            panic!("only the buyer or the seller can dispute"); // "sadece alıcı veya satıcı itiraz edebilir"
+        }
+        by.require_auth();
+        if env.ledger().timestamp() >= escrow.release_after {
+            panic!("escrow can already be released"); // "emanet zaten serbest bırakılabilir"
+        }
+        escrow.state = EscrowState::Disputed;
+        escrow.resolve_before = Some(env.ledger().timestamp() + ARBITRATION_PERIOD);
+        write_escrow(&env, escrow_id, &escrow);
+        events::escrow(&env, &by, escrow_id, EscrowState::Disputed, 0, 0, 0);
+    }
+
+    // Hakemin anlaşmazlığı çözmesini sağlar: `to_seller` kadarı satıcıya, kalanı alıcıya ödenir.
+    // Tamamı alıcıya verilirse emanet `Refunded`, aksi halde `Resolved` durumuna geçer.
+    // Karar, anlaşmazlık açıldıktan sonra `ARBITRATION_PERIOD` içinde verilmelidir.
+    pub fn resolve_escrow(env: Env, escrow_id: u64, to_seller: i128) {
+        pause::ensure_not_paused(&env);
+        let escrow = read_escrow(&env, escrow_id);
+        expect_state(&env, &escrow, &[EscrowState::Disputed]);
+        escrow.arbiter.require_auth();
+        if arbitration_expired(&env, &escrow) {
+            panic!("arbitration period has ended"); // "hakem karar süresi doldu"
+        }
+        if to_seller < 0 || to_seller > escrow.amount {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        let state = if to_seller == 0 {
+            EscrowState::Refunded
+        } else {
+            EscrowState::Resolved
+        };
+        let arbiter = escrow.arbiter.clone();
+        settle(&env, escrow_id, escrow, &arbiter, state, to_seller);
+    }
+
+    // Satıcının token'ların tamamını alıcıya iade etmesini sağlar (anlaşmazlık sırasında da).
+    // Kontrat durdurulmuşken, `reclaim` gibi sadece durdurma kuralları izin veriyorsa çalışır.
+    pub fn refund_escrow(env: Env, escrow_id: u64) {
+        if !pause::read_policy(&env).allow_reclaim {
+            pause::ensure_not_paused(&env);
+        }
+        let escrow = read_escrow(&env, escrow_id);
+        expect_state(&env, &escrow, &[EscrowState::Funded, EscrowState::Disputed]);
+        escrow.seller.require_auth();
+        let seller = escrow.seller.clone();
+        settle(&env, escrow_id, escrow, &seller, EscrowState::Refunded, 0);
+    }
+
+    // Hakem süresi içinde karar vermezse alıcının token'ların tamamını geri almasını sağlar.
+    // Kontrat durdurulmuşken, `reclaim` gibi sadece durdurma kuralları izin veriyorsa çalışır.
+    pub fn expire_dispute(env: Env, escrow_id: u64) {
+        if !pause::read_policy(&env).allow_reclaim {
+            pause::ensure_not_paused(&env);
+        }
+        let escrow = read_escrow(&env, escrow_id);
+        expect_state(&env, &escrow, &[EscrowState::Disputed]);
+        escrow.buyer.require_auth();
+        if !arbitration_expired(&env, &escrow) {
+            panic!("time predicate is not fulfilled"); // "zaman koşulu karşılanmadı"
+        }
+        let buyer = escrow.buyer.clone();
+        settle(&env, escrow_id, escrow, &buyer, EscrowState::Refunded, 0);
+    }
+
+    // Verilen kimlikteki emaneti döndürür; yoksa `None` döner. Sonuçlanan emanetler,
+    // son durumları görülebilsin diye silinmez.
+    pub fn get_escrow(env: Env, escrow_id: u64) -> Option<Escrow> {
+        env.storage().persistent().get(&DataKey::Escrow(escrow_id))
+    }
+}
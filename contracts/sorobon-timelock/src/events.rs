@@ -0,0 +1,145 @@
+// Kontratın yayınladığı olaylar (events).
+//
+// Her olayın ilk konusu (topic) olay adıdır, ikinci konusu ilgili hesaptır. Miktarlar
+// ücretler kesildikten sonraki net değerlerdir; kesilen ücret ayrıca verilir.
+use soroban_sdk::{symbol_short, Address, Env};
+
+use crate::escrow::EscrowState;
+use crate::fee::{FeeConfig, PendingFeeConfig};
+
+// Bakiye yatırıldı: veri `(bakiye_id, token, net_miktar, ücret)`.
+pub(crate) fn deposit(
+    env: &Env,
+    from: &Address,
+    balance_id: u64,
+    token: &Address,
+    amount: i128,
+    fee: i128,
+) {
+    env.events().publish(
+        (symbol_short!("deposit"), from.clone()),
+        (balance_id, token.clone(), amount, fee),
+    );
+}
+
+// Mevcut bakiyeye token eklendi: veri `(bakiye_id, token, net_miktar, ücret)`.
+pub(crate) fn top_up(
+    env: &Env,
+    from: &Address,
+    balance_id: u64,
+    token: &Address,
+    amount: i128,
+    fee: i128,
+) {
+    env.events().publish(
+        (symbol_short!("top_up"), from.clone()),
+        (balance_id, token.clone(), amount, fee),
+    );
+}
+
+// Bakiye talep edildi: veri `(bakiye_id, token, talepçiye_ödenen, ücret)`.
+pub(crate) fn claim(
+    env: &Env,
+    claimant: &Address,
+    balance_id: u64,
+    token: &Address,
+    amount: i128,
+    fee: i128,
+) {
+    env.events().publish(
+        (symbol_short!("claim"), claimant.clone()),
+        (balance_id, token.clone(), amount, fee),
+    );
+}
+
+// Bakiye erken talep edildi ve ceza kesildi: veri `(bakiye_id, token, ceza)`. Talepçiye yapılan
+// ödeme ayrıca `claim` olayıyla yayınlanır.
+pub(crate) fn penalty(
+    env: &Env,
+    claimant: &Address,
+    balance_id: u64,
+    token: &Address,
+    penalty: i128,
+) {
+    env.events().publish(
+        (symbol_short!("penalty"), claimant.clone()),
+        (balance_id, token.clone(), penalty),
+    );
+}
+
+// Süresi dolan bakiye yatıran adrese geri ödendi: veri `(bakiye_id, token, miktar)`.
+pub(crate) fn reclaim(
+    env: &Env,
+    depositor: &Address,
+    balance_id: u64,
+    token: &Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("reclaim"), depositor.clone()),
+        (balance_id, token.clone(), amount),
+    );
+}
+
+// Miras kilidinin sahibi etkin olduğunu bildirdi: veri `(bakiye_id, yeni_son_tarih)`.
+pub(crate) fn heartbeat(env: &Env, owner: &Address, balance_id: u64, deadline: u64) {
+    env.events()
+        .publish((symbol_short!("heartbeat"), owner.clone()), (balance_id, deadline));
+}
+
+// Bakiyenin kilidi uzatıldı: veri `(bakiye_id, eski_açılma_zamanı, yeni_açılma_zamanı)`.
+pub(crate) fn extend(env: &Env, by: &Address, balance_id: u64, old: u64, new: u64) {
+    env.events()
+        .publish((symbol_short!("extend"), by.clone()), (balance_id, old, new));
+}
+
+// Emanetin durumu değişti: veri `(emanet_id, yeni_durum, satıcıya_ödenen, alıcıya_iade, ücret)`.
+// İkinci konu, değişikliği yapan hesaptır (alıcı, satıcı veya hakem).
+pub(crate) fn escrow(
+    env: &Env,
+    actor: &Address,
+    escrow_id: u64,
+    state: EscrowState,
+    to_seller: i128,
+    to_buyer: i128,
+    fee: i128,
+) {
+    env.events().publish(
+        (symbol_short!("escrow"), actor.clone()),
+        (escrow_id, state, to_seller, to_buyer, fee),
+    );
+}
+
+// Yatıran bir aşamayı onayladı: veri `(bakiye_id, aşama_sırası)`.
+pub(crate) fn milestone_approved(env: &Env, depositor: &Address, grant_id: u64, index: u32) {
+    env.events().publish(
+        (symbol_short!("approved"), depositor.clone()),
+        (grant_id, index),
+    );
+}
+
+// Akış gönderen tarafından iptal edildi: veri `(akış_id, token, alıcıya_ödenen, gönderene_iade)`.
+pub(crate) fn stream_cancelled(
+    env: &Env,
+    sender: &Address,
+    stream_id: u64,
+    token: &Address,
+    to_recipient: i128,
+    to_sender: i128,
+) {
+    env.events().publish(
+        (symbol_short!("cancel"), sender.clone()),
+        (stream_id, token.clone(), to_recipient, to_sender),
+    );
+}
+
+// Yeni bir ücret tarifesi önerildi.
+pub(crate) fn fee_proposed(env: &Env, pending: &PendingFeeConfig) {
+    env.events()
+        .publish((symbol_short!("fee_prop"),), pending.clone());
+}
+
+// Önerilen ücret tarifesi yürürlüğe girdi.
+pub(crate) fn fee_applied(env: &Env, config: &FeeConfig) {
+    env.events().publish((symbol_short!("fee_set"),), config.clone());
+}
@@ -0,0 +1,108 @@
+// Kilit süresinin uzatılması ve yeniden kilitleme.
+//
+// 'After' türündeki bir bakiyenin açılma zamanı, talep edip yeniden yatırmaya gerek kalmadan
+// ileri alınabilir; daha erkene çekilemez. Açılmış bir kilit de aynı şekilde yeniden
+// kilitlenebilir. Kimin uzatabileceği her bakiye için ayrı ayarlanır (`ExtendPolicy`).
+//
+// Uzatma talepçilerin fonlara erişimini geciktirdiği için varsayılan olarak sadece talepçiler,
+// hep birlikte onay vererek uzatabilir; talepçilerden biri diğerleri adına uzatamaz. Yatıranın
+// tek başına uzatabilmesi için yatıran ve tüm talepçiler bunu önceden onaylamalıdır.
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env};
+
+use crate::{
+    events, load_balance, pause, receipt, write_balance, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, Error, TimeBoundKind,
+    BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+// Bir bakiyenin kilidini kimlerin uzatabileceği.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ExtendPolicy {
+    // Sadece tüm talepçiler birlikte uzatabilir (varsayılan).
+    Claimants,
+    // Yatıran tek başına veya tüm talepçiler birlikte uzatabilir.
+    DepositorOrClaimants,
+}
+
+fn read_policy(env: &Env, balance_id: u64) -> ExtendPolicy {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ExtendPolicy(balance_id))
+        .unwrap_or(ExtendPolicy::Claimants)
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `by`'nin, 'After' türündeki `balance_id` bakiyesinin açılma zamanını `new_timestamp`'e
+    // ertelemesini sağlar. Yeni zaman mevcut açılma zamanından kesin olarak sonra olmalıdır.
+    // `by` bir talepçiyse diğer tüm talepçilerin de yetkilendirmesi gerekir. Kurallar izin
+    // veriyorsa yatıran tek başına uzatabilir.
+    // Miras kilitlerinin süresi `heartbeat` ile yönetildiği için uzatılamaz.
+    pub fn extend_lock(env: Env, balance_id: u64, by: Address, new_timestamp: u64) {
+        pause::ensure_not_paused(&env);
+        let mut balance = load_balance(&env, balance_id);
+        // Token'laştırılmış bakiyenin talepçileri makbuz sahiplerinin aleyhine uzatma yapamaz.
+        receipt::ensure_not_tokenized(&env, balance_id);
+        if balance.time_bound.kind != TimeBoundKind::After
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::InactivityWindow(balance_id))
+        {
+            panic_with_error!(&env, Error::ExtensionNotAllowed);
+        }
+        let by_depositor = read_policy(&env, balance_id) == ExtendPolicy::DepositorOrClaimants
+            && balance.depositor.as_ref() == Some(&by);
+        if !by_depositor && !balance.claimants.contains(&by) {
+            panic_with_error!(&env, Error::ExtensionNotAllowed);
+        }
+        by.require_auth();
+        // Uzatma her talepçinin erişimini geciktirir; talepçilerden biri diğerleri adına
+        // karar veremez.
+        if !by_depositor {
+            for claimant in balance.claimants.iter() {
+                if claimant != by {
+                    claimant.require_auth();
+                }
+            }
+        }
+        if new_timestamp <= balance.time_bound.timestamp {
+            panic_with_error!(&env, Error::InvalidSchedule);
+        }
+
+        let old_timestamp = balance.time_bound.timestamp;
+        balance.time_bound.timestamp = new_timestamp;
+        write_balance(&env, balance_id, &balance);
+        events::extend(&env, &by, balance_id, old_timestamp, new_timestamp);
+    }
+
+    // Bakiyenin kilidini kimlerin uzatabileceğini değiştirir. Yatıranın ve tüm talepçilerin
+    // yetkilendirmesi gerekir. Talepçi listesi boş olan (sahiplik koşullu) bakiyelerde onay
+    // verecek talepçi olmadığı için kural değiştirilemez.
+    pub fn set_extend_policy(env: Env, balance_id: u64, policy: ExtendPolicy) {
+        let balance = load_balance(&env, balance_id);
+        if balance.claimants.is_empty() {
+            panic_with_error!(&env, Error::ExtensionNotAllowed);
+        }
+        let depositor = balance
+            .depositor
+            .unwrap_or_else(|| panic!("depositor of this balance is unknown")); // "bakiyeyi yatıran bilinmiyor"
+        depositor.require_auth();
+        for claimant in balance.claimants.iter() {
+            if claimant != depositor {
+                claimant.require_auth();
+            }
+        }
+        let key = DataKey::ExtendPolicy(balance_id);
+        env.storage().persistent().set(&key, &policy);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+
+    // Bakiyenin kilidini kimlerin uzatabileceğini döndürür.
+    pub fn extend_policy(env: Env, balance_id: u64) -> ExtendPolicy {
+        read_policy(&env, balance_id)
+    }
+}
@@ -0,0 +1,137 @@
+// Yatırma (deposit) ve talep (claim) sırasında kesilen protokol ücreti.
+//
+// Ücretler baz puan (1/10.000) cinsinden tanımlanır, `MAX_FEE_BPS` ile sınırlandırılır ve
+// hazine (treasury) adresine gönderilir. Yönetici ücret tarifesini ancak `FEE_UPDATE_DELAY`
+// saniyelik bir bekleme süresinden sonra değiştirebilir; böylece kullanıcılar yeni tarifeyi
+// yürürlüğe girmeden önce görebilir.
+use soroban_sdk::{contractimpl, contracttype, token, Address, Env};
+
+use crate::{
+    admin, events, ClaimableBalanceContract, ClaimableBalanceContractArgs,
+    ClaimableBalanceContractClient, DataKey,
+};
+
+// Baz puan paydası: 10.000 baz puan = %100.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+// Tek bir işlemde kesilebilecek en yüksek ücret: 1.000 baz puan = %10.
+pub const MAX_FEE_BPS: u32 = 1_000;
+// Önerilen bir ücret tarifesinin yürürlüğe girmeden önce beklemesi gereken süre (2 gün).
+pub const FEE_UPDATE_DELAY: u64 = 2 * 24 * 60 * 60;
+
+// Ücret tarifesi. Örnek (instance) depolamada tutulur.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FeeConfig {
+    // Kesilen ücretlerin gönderileceği adres.
+    pub treasury: Address,
+    // Yatırılan miktardan kesilen ücret (baz puan).
+    pub deposit_bps: u32,
+    // Talep edilen miktardan kesilen ücret (baz puan).
+    pub claim_bps: u32,
+}
+
+// Bekleme süresi dolmamış, önerilmiş bir ücret tarifesi.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingFeeConfig {
+    pub config: FeeConfig,
+    // Tarifenin uygulanabileceği en erken zaman damgası.
+    pub effective_at: u64,
+}
+
+// Yürürlükteki ücret tarifesini okur. Hiç tarife uygulanmamışsa ücret kesilmez (`None`).
+pub(crate) fn read_config(env: &Env) -> Option<FeeConfig> {
+    env.storage().instance().get(&DataKey::FeeConfig)
+}
+
+// `amount` üzerinden `bps` baz puanlık ücreti hesaplar. Sonuç aşağı yuvarlanır,
+// yani artık kesirler her zaman kullanıcının lehinedir.
+pub(crate) fn compute_fee(amount: i128, bps: u32) -> i128 {
+    amount
+        .checked_mul(bps as i128)
+        .unwrap_or_else(|| panic!("fee calculation overflow")) // "ücret hesabı taştı"
+        / BPS_DENOMINATOR
+}
+
+// Kontratın elindeki `amount` kadar token'dan yatırma ücretini keser ve hazineye gönderir.
+// Bakiyeye kaydedilecek net miktarı ve kesilen ücreti döndürür.
+pub(crate) fn charge_deposit_fee(env: &Env, token: &Address, amount: i128) -> (i128, i128) {
+    match read_config(env) {
+        Some(config) => charge(env, token, &config.treasury, amount, config.deposit_bps),
+        None => (amount, 0),
+    }
+}
+
+// Talep edilen `amount` miktarından talep ücretini keser ve hazineye gönderir.
+// Talepçiye ödenecek net miktarı ve kesilen ücreti döndürür.
+pub(crate) fn charge_claim_fee(env: &Env, token: &Address, amount: i128) -> (i128, i128) {
+    match read_config(env) {
+        Some(config) => charge(env, token, &config.treasury, amount, config.claim_bps),
+        None => (amount, 0),
+    }
+}
+
+fn charge(env: &Env, token: &Address, treasury: &Address, amount: i128, bps: u32) -> (i128, i128) {
+    let fee = compute_fee(amount, bps);
+    if fee > 0 {
+        token::Client::new(env, token).transfer(&env.current_contract_address(), treasury, &fee);
+    }
+    (amount - fee, fee)
+}
+
+// Ücret tarifesinin sınırlar içinde olduğunu kontrol eder.
+fn validate(config: &FeeConfig) {
+    if config.deposit_bps > MAX_FEE_BPS || config.claim_bps > MAX_FEE_BPS {
+        panic!("fee exceeds maximum"); // "ücret üst sınırı aşıyor"
+    }
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // Yeni bir ücret tarifesi önerir. Sadece yönetici çağırabilir.
+    // Tarife `FEE_UPDATE_DELAY` saniye sonra `apply_fee_config` ile yürürlüğe girer.
+    // Bekleyen bir öneri varsa yenisiyle değiştirilir ve bekleme süresi yeniden başlar.
+    pub fn propose_fee_config(env: Env, config: FeeConfig) {
+        admin::require_admin(&env);
+        validate(&config);
+
+        let pending = PendingFeeConfig {
+            config,
+            effective_at: env.ledger().timestamp() + FEE_UPDATE_DELAY,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingFeeConfig, &pending);
+        events::fee_proposed(&env, &pending);
+    }
+
+    // Bekleme süresi dolmuş olan öneriyi yürürlüğe koyar. Sadece yönetici çağırabilir.
+    pub fn apply_fee_config(env: Env) {
+        admin::require_admin(&env);
+
+        let pending: PendingFeeConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingFeeConfig)
+            .unwrap_or_else(|| panic!("no pending fee config")); // "bekleyen tarife yok"
+        if env.ledger().timestamp() < pending.effective_at {
+            panic!("fee config update is still timelocked"); // "tarife hâlâ beklemede"
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::FeeConfig, &pending.config);
+        env.storage().instance().remove(&DataKey::PendingFeeConfig);
+        events::fee_applied(&env, &pending.config);
+    }
+
+    // Yürürlükteki ücret tarifesini döndürür.
+    pub fn fee_config(env: Env) -> Option<FeeConfig> {
+        read_config(&env)
+    }
+
+    // Bekleyen ücret tarifesi önerisini döndürür.
+    pub fn pending_fee_config(env: Env) -> Option<PendingFeeConfig> {
+        env.storage().instance().get(&DataKey::PendingFeeConfig)
+    }
+}
@@ -0,0 +1,140 @@
+// Ölü adam anahtarı (dead-man's switch) / miras kilitleri.
+//
+// Sahip (yatıran) bakiyenin tam kontrolünü elinde tutar ve istediği zaman çekebilir. Belirlenen
+// hareketsizlik süresi içinde `heartbeat` çağırmazsa, mirasçılar (talepçiler) bakiyeyi
+// `claim` ile talep edebilir hale gelir.
+//
+// Kilit, 'After' türünde zaman sınırı olan sıradan bir bakiyedir; farkı, sınırın sabit
+// olmaması ve sahibin her işleminde `şimdi + hareketsizlik_süresi` anına ertelenmesidir.
+// Hareketsizlik süresi bakiyenin yanında ayrı bir anahtarda tutulur.
+use soroban_sdk::{contractimpl, panic_with_error, token, Address, Env, Vec};
+
+use crate::{
+    check_deposit, create_balance_at, events, load_balance, next_balance_id, pause, receive_tokens,
+    registry, remove_balance, write_balance, BalanceOutcome, ClaimableBalance,
+    ClaimableBalanceContract, ClaimableBalanceContractArgs, ClaimableBalanceContractClient,
+    DataKey, Error, TimeBound, TimeBoundKind, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+// Bakiyenin hareketsizlik süresini okur; bakiye bir miras kilidi değilse `Error::NotInheritanceLock`
+// ile paniklet.
+fn read_window(env: &Env, balance_id: u64) -> u64 {
+    let key = DataKey::InactivityWindow(balance_id);
+    let window = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, Error::NotInheritanceLock));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    window
+}
+
+// Mirasçıların talep edebileceği anı `şimdi + window` olarak yeniler ve olayı yayınlar.
+fn reset_deadline(env: &Env, balance_id: u64, balance: &mut ClaimableBalance, window: u64) {
+    balance.time_bound.timestamp = env.ledger().timestamp().saturating_add(window);
+    events::heartbeat(
+        env,
+        balance.depositor.as_ref().unwrap(),
+        balance_id,
+        balance.time_bound.timestamp,
+    );
+}
+
+// Sahibin yetkilendirmesini ister ve miras kilidini ve hareketsizlik süresini döndürür.
+fn load_lock(env: &Env, balance_id: u64) -> (ClaimableBalance, u64) {
+    let balance = load_balance(env, balance_id);
+    let window = read_window(env, balance_id);
+    // Miras kilitleri her zaman `deposit_inheritance` ile oluşturulduğu için yatıran bilinir.
+    balance.depositor.as_ref().unwrap().require_auth();
+    (balance, window)
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `from`'dan `amount` kadar token yatırarak bir miras kilidi oluşturur ve kimliğini döndürür.
+    // `from` `inactivity_window` saniye boyunca hareketsiz kalırsa `heirs` bakiyeyi talep edebilir.
+    pub fn deposit_inheritance(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        heirs: Vec<Address>,
+        inactivity_window: u64,
+    ) -> u64 {
+        check_deposit(&env, amount, &heirs);
+        if inactivity_window == 0 {
+            panic_with_error!(&env, Error::InvalidSchedule);
+        }
+        from.require_auth();
+        registry::ensure_allowed(&env, &from);
+
+        let amount = receive_tokens(&env, &token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &amount)
+        });
+        let time_bound = TimeBound {
+            kind: TimeBoundKind::After,
+            timestamp: env.ledger().timestamp().saturating_add(inactivity_window),
+        };
+        // Hareketsizlik süresi bakiyeden önce kaydedilir; böylece bakiye yazılırken kilit miras
+        // kilidi olarak tanınır ve oy gücü vermez.
+        let balance_id = next_balance_id(&env);
+        let key = DataKey::InactivityWindow(balance_id);
+        env.storage().persistent().set(&key, &inactivity_window);
+        env.storage().persistent().extend_ttl(
+            &key,
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        );
+        create_balance_at(&env, balance_id, from, token, amount, heirs, time_bound);
+        balance_id
+    }
+
+    // Sahibin hâlâ etkin olduğunu bildirir ve mirasçıların talep edebileceği anı ileri alır.
+    // Süre dolmuş olsa bile, mirasçılar talep etmediği sürece sahip kilidi yeniden kurabilir.
+    pub fn heartbeat(env: Env, balance_id: u64) {
+        let (mut balance, window) = load_lock(&env, balance_id);
+        reset_deadline(&env, balance_id, &mut balance, window);
+        write_balance(&env, balance_id, &balance);
+    }
+
+    // Sahibin kilitten `amount` kadar token çekmesini sağlar. Çekim de bir etkinlik sayılır
+    // ve süreyi yeniler. Bakiyenin tamamı çekilirse kilit kapanır.
+    // Kontrat durdurulmuşken, `reclaim` gibi sadece durdurma kuralları izin veriyorsa çalışır.
+    pub fn withdraw_inheritance(env: Env, balance_id: u64, amount: i128) {
+        if !pause::read_policy(&env).allow_reclaim {
+            pause::ensure_not_paused(&env);
+        }
+        let (mut balance, window) = load_lock(&env, balance_id);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if amount > balance.amount {
+            panic_with_error!(&env, Error::AmountExceedsBalance);
+        }
+
+        let owner = balance.depositor.clone().unwrap();
+        registry::ensure_allowed(&env, &owner);
+        balance.amount -= amount;
+        if balance.amount == 0 {
+            remove_balance(&env, balance_id, BalanceOutcome::Reclaimed);
+        } else {
+            reset_deadline(&env, balance_id, &mut balance, window);
+            write_balance(&env, balance_id, &balance);
+        }
+        token::Client::new(&env, &balance.token).transfer(
+            &env.current_contract_address(),
+            &owner,
+            &amount,
+        );
+        events::reclaim(&env, &owner, balance_id, &balance.token, amount);
+    }
+
+    // Bakiyenin hareketsizlik süresini döndürür; bakiye bir miras kilidi değilse `None` döner.
+    pub fn inactivity_window(env: Env, balance_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::InactivityWindow(balance_id))
+    }
+}
@@ -4,11 +4,68 @@
 // - `contract`: Bir struct'ı Soroban kontratı olarak işaretlemek için kullanılır.
 // - `contractimpl`: Bir struct için kontrat fonksiyonlarını (arayüzünü) implemente etmek için kullanılır.
 // - `contracttype`: Bir enum veya struct'ı kontrat depolaması ve arayüzleri için uygun hale getirmek için kullanılır (serileştirme/deserileştirme).
+// - `panic_with_error`: Kontratı tipli bir hatayla (`Error`) durdurmak için kullanılır.
 // - `token`: Standart token kontratlarıyla etkileşim kurmak için bir istemci sağlar.
 // - `Address`: Bir Soroban hesabını veya kontratını temsil eden bir adres tipidir.
 // - `Env`: Kontratın çalıştığı Soroban ortamına erişim sağlar (örn: depolama, defter bilgisi, vb.).
 // - `Vec`: Dinamik boyutlu bir vektör (liste) tipidir.
-use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, token, Address, Env, Vec,
+};
+
+mod admin;
+mod allowance;
+mod batch;
+mod conditions;
+mod errors;
+mod escrow;
+mod events;
+mod extension;
+mod fee;
+mod inheritance;
+mod merkle;
+mod milestones;
+mod pause;
+mod penalty;
+mod receipt;
+mod recurring;
+mod registry;
+mod stream;
+mod tiers;
+mod top_up;
+mod upgrade;
+mod validation;
+mod voting;
+mod voucher;
+
+pub use batch::LockSpec;
+pub use conditions::{
+    Asset, BalanceOutcome, Comparison, Condition, HoldingCondition, Oracle, OracleClient,
+    PriceCondition, PriceData,
+};
+pub use errors::Error;
+pub use escrow::{Escrow, EscrowState};
+pub use extension::ExtendPolicy;
+pub use fee::{FeeConfig, PendingFeeConfig};
+pub use merkle::MerkleDistribution;
+pub use milestones::{Milestone, MilestoneGrant, MilestoneStatus, MilestoneTerms};
+pub use pause::PausePolicy;
+pub use penalty::PenaltyConfig;
+pub use receipt::{Receipt, ReceiptClient};
+pub use recurring::{RecurringPayout, RecurringSchedule};
+pub use registry::{Registry, RegistryClient};
+pub use stream::Stream;
+pub use tiers::ClaimantTier;
+pub use top_up::TopUpPolicy;
+pub use voucher::VoucherBalance;
+pub use voting::{VotingCheckpoint, VotingLock};
+
+// Bir gündeki yaklaşık defter (ledger) sayısı (~5 saniyelik kapanış süresiyle).
+const DAY_IN_LEDGERS: u32 = 17280;
+// Kalıcı (persistent) depolamadaki bakiye kaydının ömrü bu eşiğin altına düştüğünde
+// `BALANCE_BUMP_AMOUNT` kadar uzatılır. Zaman kilitleri uzun sürebildiği için kayıt canlı tutulur.
+pub(crate) const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 // `#[derive(Clone)]`: Bu enum'un kopyalanabilir (cloneable) olmasını sağlar.
 // `#[contracttype]`: Bu enum'un kontrat depolamasında anahtar veya değer olarak kullanılabilmesi için
@@ -16,14 +73,83 @@ use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, Vec
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
-    // Kontratın başlatılıp başlatılmadığını kontrol etmek için kullanılacak bir depolama anahtarı.
-    Init,
-    // Talep edilebilir bakiyenin bilgilerini saklamak için kullanılacak bir depolama anahtarı.
-    Balance,
+    // Bir sonraki bakiyeye verilecek kimlik (id). Kimlikler 0'dan başlar ve hiç tekrar kullanılmaz.
+    NextId,
+    // Verilen kimlikteki talep edilebilir bakiyenin bilgilerini saklamak için kullanılan anahtar.
+    // Kalıcı (persistent) depolamada tutulur; bakiye talep edildiğinde veya geri alındığında silinir.
+    Balance(u64),
+    // Yükseltme (upgrade) ve göç (migrate) işlemlerini yetkilendiren yönetici adresi.
+    Admin,
+    // Depolamadaki verinin hangi şema sürümüne göre yazıldığını tutar.
+    Version,
+    // Kontratı acil durumda durdurabilen koruyucu (guardian) adres.
+    Guardian,
+    // Durdurma kuralları (en uzun süre, durdurulmuşken geri alma izni).
+    PausePolicy,
+    // Son durdurmanın sona erdiği veya ereceği zaman damgası.
+    Paused,
+    // Yürürlükteki ücret tarifesi.
+    FeeConfig,
+    // Bekleme süresi dolmamış ücret tarifesi önerisi.
+    PendingFeeConfig,
+    // Bir bakiyede izin verilen en yüksek talepçi sayısı.
+    MaxClaimants,
+    // (yatıran, operatör): Operatörün, yatıranın kontrata verdiği harcama izninden
+    // (allowance) onun adına yatırma yapıp yapamayacağı.
+    Operator(Address, Address),
+    // Merkle köküyle tanımlanan dağıtım. Kimliği bakiyelerle aynı sayaçtan alır.
+    Distribution(u64),
+    // (dağıtım_id, kayıt_no): Dağıtımda talep edilmiş yaprakların bit haritası.
+    // Her kayıt 64 yaprağı tutar; `i` sıradaki yaprak `i / 64` numaralı kayıttadır.
+    ClaimedLeaves(u64, u32),
+    // İmzalı kuponlarla talep edilen bakiye. Kimliği bakiyelerle aynı sayaçtan alır.
+    VoucherBalance(u64),
+    // (bakiye_id, nonce): Bu bakiyede kullanılmış kupon numarası.
+    VoucherNonce(u64, u64),
+    // Saniye başına akan ödeme akışı. Kimliği bakiyelerle aynı sayaçtan alır.
+    Stream(u64),
+    // Dönemsel ödeme planı. Kimliği bakiyelerle aynı sayaçtan alır.
+    Recurring(u64),
+    // Miras kilidi olan bakiyenin hareketsizlik süresi (saniye). Bakiye silinince o da silinir.
+    InactivityWindow(u64),
+    // Kademeli bakiyenin talepçi kademeleri. Bakiye silinince o da silinir.
+    Tiers(u64),
+    // Hakemli iki taraflı emanet. Kimliği bakiyelerle aynı sayaçtan alır.
+    Escrow(u64),
+    // Aşamalı (milestone) bakiye. Kimliği bakiyelerle aynı sayaçtan alır.
+    Milestones(u64),
+    // Koşullu bakiyenin talep koşulu. Bakiye silinince o da silinir.
+    Condition(u64),
+    // Bu bakiyeye bağlı (onun kapanmasını bekleyen) en az bir bakiye olduğunu işaretler.
+    HasDependents(u64),
+    // Bağımlısı olan bir bakiyenin nasıl kapandığı (talep edildi veya geri alındı).
+    Outcome(u64),
+    // Yatıran ve talepçilerin sorulduğu izin listesi / KYC kayıt kontratı.
+    Registry,
+    // Erken talep kuralları (ceza oranı, azalma süresi ve lehtar).
+    PenaltyConfig,
+    // Bakiyenin kilidini kimlerin uzatabileceği (`ExtendPolicy`). Ayarlanmamışsa sadece talepçiler.
+    ExtendPolicy(u64),
+    // Oy gücü veren token.
+    VotingToken,
+    // Adresin oy gücü kontrol noktası sayısı.
+    VotingCheckpoints(Address),
+    // Adresin verilen sıradaki oy gücü kontrol noktası.
+    VotingCheckpoint(Address, u32),
+    // Adresin verilen andaki oy gücü eğim değişikliği.
+    VotingSlopeChange(Address, u64),
+    // Adresin henüz işlenmemiş eğim değişikliklerinin anları.
+    VotingSlopeTimes(Address),
+    // Bakiyenin oy kilidi olarak katkısı.
+    VotingLock(u64),
+    // Makbuz token'larının deploy edileceği Wasm'ın özeti.
+    ReceiptWasm,
+    // Token'laştırılmış bakiyenin makbuz token'ı.
+    Receipt(u64),
 }
 
 // `#[derive(Clone)]` ve `#[contracttype]` yukarıdaki `DataKey` ile aynı amaçla kullanılır.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub enum TimeBoundKind {
     // Zaman sınırının, belirtilen bir zamandan "önce" olduğunu ifade eder.
@@ -33,7 +159,7 @@ pub enum TimeBoundKind {
 }
 
 // `#[derive(Clone)]` ve `#[contracttype]` yukarıdaki `DataKey` ile aynı amaçla kullanılır.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct TimeBound {
     // Zaman sınırının türünü (Before veya After) tutar.
@@ -43,7 +169,7 @@ pub struct TimeBound {
 }
 
 // `#[derive(Clone)]` ve `#[contracttype]` yukarıdaki `DataKey` ile aynı amaçla kullanılır.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct ClaimableBalance {
     // Talep edilebilir bakiyenin token türünün adresi.
@@ -54,6 +180,10 @@ pub struct ClaimableBalance {
     pub claimants: Vec<Address>,
     // Bakiyenin ne zaman talep edilebileceğini belirleyen zaman sınırı.
     pub time_bound: TimeBound,
+    // Token'ları yatıran adres. Şema sürümü 3'ten önce yatırılan bakiyelerde bilinmez (`None`).
+    pub depositor: Option<Address>,
+    // Bakiyeye sonradan kimlerin token ekleyebileceği (`top_up`).
+    pub top_up_policy: TopUpPolicy,
 }
 
 // `#[contract]` makrosu, bu struct'ı bir Soroban akıllı kontratı olarak tanımlar.
@@ -67,7 +197,7 @@ pub struct ClaimableBalanceContract;
 // `env`: Kontrat ortamına erişim.
 // `time_bound`: Kontrol edilecek zaman sınırı bilgisi.
 // `-> bool`: Fonksiyonun bir boolean (true/false) değer döndüreceğini belirtir.
-fn check_time_bound(env: &Env, time_bound: &TimeBound) -> bool {
+pub(crate) fn check_time_bound(env: &Env, time_bound: &TimeBound) -> bool {
     // Mevcut defterin (ledger) zaman damgasını alır. Bu, blok zincirinin mevcut zamanıdır.
     let ledger_timestamp = env.ledger().timestamp();
 
@@ -84,11 +214,29 @@ fn check_time_bound(env: &Env, time_bound: &TimeBound) -> bool {
     }
 }
 
+// Zaman sınırının bir daha asla karşılanamayacağını (süresinin dolduğunu) kontrol eder.
+// Sadece 'Before' türündeki sınırlar dolabilir; 'After' sınırı bir kez açıldıktan sonra açık kalır.
+pub(crate) fn is_expired(env: &Env, time_bound: &TimeBound) -> bool {
+    match time_bound.kind {
+        TimeBoundKind::Before => env.ledger().timestamp() > time_bound.timestamp,
+        TimeBoundKind::After => false,
+    }
+}
+
 // `#[contractimpl]` makrosu, `ClaimableBalanceContract` için kontrat fonksiyonlarını implemente eder.
 // Bu blok içindeki public fonksiyonlar, kontratın dışarıdan çağrılabilir arayüzünü oluşturur.
 #[contractimpl]
 impl ClaimableBalanceContract {
+    // Kontrat deploy edilirken bir kez çalışan kurucu (constructor) fonksiyon.
+    // `admin`: Kontratı yükseltme ve depolama göçü yapma yetkisine sahip adres.
+    pub fn __constructor(env: Env, admin: Address) {
+        admin::write_admin(&env, &admin);
+        // Yeni deploy edilen bir kontratın depolaması her zaman güncel şemadadır.
+        upgrade::write_version(&env, upgrade::SCHEMA_VERSION);
+    }
+
     // `deposit` fonksiyonu, bir kullanıcı tarafından token yatırılmasına ve talep edilebilir bir bakiye oluşturulmasına olanak tanır.
+    // Her yatırma yeni bir bakiye oluşturur ve bu bakiyenin kimliğini (id) döndürür.
     // `env`: Kontrat ortamı.
     // `from`: Token'ları yatıran hesabın adresi.
     // `token`: Yatırılan token'ın kontrat adresi.
@@ -102,86 +250,276 @@ impl ClaimableBalanceContract {
         amount: i128,
         claimants: Vec<Address>,
         time_bound: TimeBound,
-    ) {
-        // Talep edenlerin sayısı 10'dan fazlaysa, işlemi paniklet (durdur ve geri al).
-        // Bu, kontratın aşırı büyük bir talepçi listesiyle şişirilmesini önler.
-        if claimants.len() > 10 {
-            panic!("too many claimants"); // "çok fazla talepçi"
-        }
-        // Eğer kontrat `is_initialized` fonksiyonuna göre zaten başlatılmışsa, paniklet.
-        // Bu kontratın sadece bir kez (tek bir talep edilebilir bakiye için) kullanılması amaçlanmıştır.
-        if is_initialized(&env) {
-            panic!("contract has been already initialized"); // "kontrat zaten başlatılmış"
-        }
+    ) -> u64 {
+        // Kontratın yeni bir yatırımı kabul edebildiğini ve girdilerin geçerli olduğunu kontrol et.
+        check_deposit(&env, amount, &claimants);
         // `from` adresinin bu `deposit` çağrısını tüm argümanlarla birlikte yetkilendirdiğinden emin ol.
         // Bu, `from` hesabının işlemi onayladığını garanti eder.
         from.require_auth();
+        // Kayıt kontratı atanmışsa yatıranın izinli olduğunu, token'lar hareket etmeden önce kontrol et.
+        registry::ensure_allowed(&env, &from);
 
         // Token'ları `from` adresinden bu kontratın adresine transfer et.
-        // `token::Client::new` ile belirtilen token kontratı için bir istemci oluşturulur.
         // `.transfer` fonksiyonu ile `from`'dan `env.current_contract_address()`'a (bu kontratın adresi) `amount` kadar token transfer edilir.
-        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
-        // Talep edenlerden birinin bakiyeyi talep etmesine izin vermek için gerekli tüm bilgileri sakla.
-        // `env.storage().instance()` ile kontratın örnek depolamasına erişilir.
-        // `.set` ile `DataKey::Balance` anahtarı altına `ClaimableBalance` struct'ı kaydedilir.
-        env.storage().instance().set(
-            &DataKey::Balance, // Anahtar olarak DataKey::Balance kullanılır.
-            &ClaimableBalance { // Değer olarak ClaimableBalance struct'ı kullanılır.
-                token,          // Token adresi.
-                amount,         // Token miktarı.
-                time_bound,     // Zaman sınırı.
-                claimants,      // Talepçiler listesi.
-            },
-        );
-        // Kontratı başlatılmış olarak işaretle, tekrar kullanılmasını önlemek için.
-        // Bu, başlatmayı ele almanın sadece bir yoludur - bir kontratın birden fazla
-        // talep edilebilir bakiyeyi yönetmesine izin vermek de mümkün olabilir.
-        // `DataKey::Init` anahtarı altına boş bir tuple `()` kaydedilerek kontratın başlatıldığı işaretlenir.
-        env.storage().instance().set(&DataKey::Init, &());
+        // Transferde ücret kesen veya bakiyesi kendiliğinden değişen token'larda kontrata `amount`'tan
+        // az token ulaşabilir; bu yüzden bakiyeye gerçekten alınan miktar kaydedilir.
+        let amount = receive_tokens(&env, &token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &amount)
+        });
+        // Alınan token'larla talep edilebilir bakiyeyi oluştur ve kimliğini döndür.
+        create_balance(&env, from, token, amount, claimants, time_bound)
     }
 
     // `claim` fonksiyonu, yetkili bir talepçinin depolanmış bakiyeyi talep etmesine olanak tanır.
     // `env`: Kontrat ortamı.
+    // `balance_id`: Talep edilen bakiyenin kimliği.
     // `claimant`: Bakiyeyi talep eden hesabın adresi.
-    pub fn claim(env: Env, claimant: Address) {
+    pub fn claim(env: Env, balance_id: u64, claimant: Address) {
+        // Kontrat acil durumda durdurulmuşsa talepleri kabul etme.
+        pause::ensure_not_paused(&env);
         // Talepçinin bu çağrıyı yetkilendirdiğinden emin ol, bu da kimliklerini doğrular.
         claimant.require_auth();
         // Sadece bakiyeyi al - eğer talep edilmişse (yani `DataKey::Balance` silinmişse),
-        // `.get()` fonksiyonundan sonraki `.unwrap()` panikleyerek kontratın çalışmasını sonlandıracaktır.
-        let claimable_balance: ClaimableBalance =
-            env.storage().instance().get(&DataKey::Balance).unwrap(); // `unwrap` ile Option<ClaimableBalance> içinden değeri alır veya panikler.
+        // `load_balance` `Error::BalanceNotFound` ile panikleyerek kontratın çalışmasını sonlandıracaktır.
+        let claimable_balance: ClaimableBalance = load_balance(&env, balance_id);
 
         // `check_time_bound` fonksiyonunu kullanarak zaman koşulunun karşılanıp karşılanmadığını kontrol et.
         if !check_time_bound(&env, &claimable_balance.time_bound) {
             panic!("time predicate is not fulfilled"); // "zaman koşulu karşılanmadı"
         }
 
-        // Talep edilebilir bakiyedeki talepçi listesini al.
-        let claimants = &claimable_balance.claimants;
-        // Çağrıyı yapan `claimant`'ın, izin verilen talepçiler listesinde olup olmadığını kontrol et.
-        if !claimants.contains(&claimant) {
-            panic!("claimant is not allowed to claim this balance"); // "talepçi bu bakiyeyi talep etmeye yetkili değil"
+        // Talepçinin bu bakiyeyi talep etmeye yetkili olduğunu ve koşulların sağlandığını kontrol et.
+        check_claimant(&env, balance_id, &claimable_balance, &claimant);
+        // Bakiyenin tamamını (talep ücreti kesilerek) talepçiye öde ve bakiyeyi kapat.
+        pay_claim(
+            &env,
+            balance_id,
+            &claimable_balance,
+            &claimant,
+            claimable_balance.amount,
+        );
+    }
+
+    // `reclaim` fonksiyonu, süresi dolduğu için artık kimse tarafından talep edilemeyecek
+    // bir bakiyenin, token'ları yatıran adrese geri ödenmesini sağlar.
+    // Kontrat durdurulmuşken bu yol, sadece durdurma kuralları izin veriyorsa açıktır.
+    pub fn reclaim(env: Env, balance_id: u64) {
+        if !pause::read_policy(&env).allow_reclaim {
+            pause::ensure_not_paused(&env);
+        }
+        let claimable_balance = load_balance(&env, balance_id);
+
+        // Yatıran adresi bilinmeyen (şema sürümü 3'ten önceki) bakiyeler geri alınamaz.
+        let depositor = claimable_balance
+            .depositor
+            .unwrap_or_else(|| panic!("depositor of this balance is unknown")); // "bakiyeyi yatıran bilinmiyor"
+        depositor.require_auth();
+        registry::ensure_allowed(&env, &depositor);
+
+        // Bakiye hâlâ talep edilebiliyorsa, talepçilerin hakkı korunur.
+        if !is_expired(&env, &claimable_balance.time_bound) {
+            panic!("balance can still be claimed"); // "bakiye hâlâ talep edilebilir"
         }
 
-        // Tüm kontroller geçildikten sonra, saklanan miktardaki token'ı talepçiye transfer et.
-        // Token'lar bu kontratın adresinden (`env.current_contract_address()`) `claimant`'a transfer edilir.
         token::Client::new(&env, &claimable_balance.token).transfer(
             &env.current_contract_address(),
-            &claimant,
+            &depositor,
             &claimable_balance.amount,
         );
-        // Daha fazla talep yapılmasını önlemek için bakiye kaydını kaldır.
-        // Bu, aynı bakiyenin birden fazla kez talep edilmesini engeller.
-        env.storage().instance().remove(&DataKey::Balance);
+        events::reclaim(
+            &env,
+            &depositor,
+            balance_id,
+            &claimable_balance.token,
+            claimable_balance.amount,
+        );
+        remove_balance(&env, balance_id, BalanceOutcome::Reclaimed);
+    }
+
+    // Verilen kimlikteki bakiyeyi döndürür. Bakiye yoksa (hiç oluşturulmamış, talep edilmiş
+    // veya geri alınmışsa) `None` döner.
+    pub fn get_balance(env: Env, balance_id: u64) -> Option<ClaimableBalance> {
+        read_balance(&env, balance_id)
+    }
+}
+
+// `claimant`'ın bakiyeyi talep etmeye yetkili olduğunu ve bakiyenin ek koşullarının sağlandığını
+// kontrol eder (zaman sınırı hariç); değilse paniklet.
+pub(crate) fn check_claimant(
+    env: &Env,
+    balance_id: u64,
+    claimable_balance: &ClaimableBalance,
+    claimant: &Address,
+) {
+    // Bakiye token'laştırıldıysa talep hakkı makbuz sahiplerindedir.
+    receipt::ensure_not_tokenized(env, balance_id);
+    // Talep edilebilir bakiyedeki talepçi listesini al.
+    let claimants = &claimable_balance.claimants;
+    // Çağrıyı yapan `claimant`'ın, izin verilen talepçiler listesinde olup olmadığını kontrol et.
+    // Boş liste sadece sahiplik koşulu kayıtlı bakiyelerde herkese açıktır; bunları koşulu
+    // sağlayan herkes talep edebilir. Koşulsuz boş listeli bir bakiyeyi kimse talep edemez.
+    let open_to_holders =
+        claimants.is_empty() && conditions::has_holding_condition(env, balance_id);
+    if !open_to_holders && !claimants.contains(claimant) {
+        panic!("claimant is not allowed to claim this balance"); // "talepçi bu bakiyeyi talep etmeye yetkili değil"
+    }
+    // Kayıt kontratı atanmışsa talepçinin izinli olduğunu kontrol et.
+    registry::ensure_allowed(env, claimant);
+    // Bakiye kademeliyse, talepçinin kademesinin şu anda açık olduğunu kontrol et.
+    tiers::check_tier(env, balance_id, claimant);
+    // Bakiye koşulluysa (ör. kâhin fiyatı), koşulun sağlandığını kontrol et.
+    conditions::check_condition(env, balance_id, claimant);
+}
+
+// Bakiyeden `amount` kadarını, talep ücreti kesilerek `claimant`'a öder ve bakiyeyi kapatır.
+// Kontratın elinde kalan fark (ör. erken talep cezası) çağıran tarafından önceden dağıtılmalıdır.
+// Talepçiye ödenen net miktarı döndürür.
+pub(crate) fn pay_claim(
+    env: &Env,
+    balance_id: u64,
+    claimable_balance: &ClaimableBalance,
+    claimant: &Address,
+    amount: i128,
+) -> i128 {
+    // Ücret tarifesi varsa talep ücretini hazineye gönder.
+    let (amount, fee) = fee::charge_claim_fee(env, &claimable_balance.token, amount);
+    // Tüm kontroller geçildikten sonra, kalan net miktardaki token'ı talepçiye transfer et.
+    // Token'lar bu kontratın adresinden (`env.current_contract_address()`) `claimant`'a transfer edilir.
+    token::Client::new(env, &claimable_balance.token).transfer(
+        &env.current_contract_address(),
+        claimant,
+        &amount,
+    );
+    events::claim(env, claimant, balance_id, &claimable_balance.token, amount, fee);
+    // Daha fazla talep yapılmasını önlemek için bakiye kaydını kaldır.
+    // Bu, aynı bakiyenin birden fazla kez talep edilmesini engeller.
+    remove_balance(env, balance_id, BalanceOutcome::Claimed);
+    amount
+}
+
+// Yeni bir yatırımın kabul edilebileceğini kontrol eder. Token çekilmeden önce çağrılır.
+pub(crate) fn check_deposit(env: &Env, amount: i128, claimants: &Vec<Address>) {
+    // Kontrat acil durumda durdurulmuşsa yeni yatırımları kabul etme.
+    pause::ensure_not_paused(env);
+    // Miktar pozitif olmalı; talepçi listesi boş olmamalı, yapılandırılmış sınırı
+    // (varsayılan 10) aşmamalı ve aynı adresi iki kez içermemeli.
+    // Aksi halde işlem tipli bir hatayla (`Error`) geri alınır.
+    validation::validate_deposit(env, amount, claimants);
+}
+
+// Kontrata ulaşmış `received` miktarındaki token'la yeni bir talep edilebilir bakiye oluşturur.
+// Yatırma ücretini keser, olayı yayınlar ve yeni bakiyenin kimliğini döndürür.
+pub(crate) fn create_balance(
+    env: &Env,
+    from: Address,
+    token: Address,
+    received: i128,
+    claimants: Vec<Address>,
+    time_bound: TimeBound,
+) -> u64 {
+    let balance_id = next_balance_id(env);
+    create_balance_at(env, balance_id, from, token, received, claimants, time_bound);
+    balance_id
+}
+
+// `create_balance` gibi, ancak `next_balance_id` ile önceden ayrılmış `balance_id` kimliğini
+// kullanır. Bakiyeye bağlı ek kayıtların bakiyeden önce yazılması gerektiğinde kullanılır.
+pub(crate) fn create_balance_at(
+    env: &Env,
+    balance_id: u64,
+    from: Address,
+    token: Address,
+    received: i128,
+    claimants: Vec<Address>,
+    time_bound: TimeBound,
+) {
+    // Ücret tarifesi varsa yatırma ücretini hazineye gönder; bakiyeye net miktar kaydedilir.
+    let (amount, fee) = fee::charge_deposit_fee(env, &token, received);
+    events::deposit(env, &from, balance_id, &token, amount, fee);
+    // Talep edenlerden birinin bakiyeyi talep etmesine izin vermek için gerekli tüm bilgileri sakla.
+    // Bakiye `write_balance` ile kalıcı (persistent) depolamaya yazılır.
+    write_balance(
+        env,
+        balance_id,
+        &ClaimableBalance {
+            token,                 // Token adresi.
+            amount,                // Token miktarı.
+            time_bound,            // Zaman sınırı.
+            claimants,             // Talepçiler listesi.
+            depositor: Some(from), // Yatıran adres (süresi dolan bakiyeyi geri alabilmesi için).
+            top_up_policy: TopUpPolicy::DepositorOnly, // Varsayılan olarak sadece yatıran ekleyebilir.
+        },
+    );
+}
+
+// Yeni bir bakiye için kimlik ayırır ve sayacı bir artırır.
+pub(crate) fn next_balance_id(env: &Env) -> u64 {
+    let balance_id: u64 = env.storage().instance().get(&DataKey::NextId).unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextId, &(balance_id + 1));
+    balance_id
+}
+
+// `pull` ile token'ları kontrata çeker ve kontratın token bakiyesinin transfer öncesi ve
+// sonrasındaki farkını, yani kontrata gerçekten ulaşan miktarı döndürür.
+// Hiç token ulaşmadıysa `Error::NothingReceived` ile paniklet.
+pub(crate) fn receive_tokens(env: &Env, token: &Address, pull: impl FnOnce(&token::Client)) -> i128 {
+    let client = token::Client::new(env, token);
+    let contract = env.current_contract_address();
+    let before = client.balance(&contract);
+    pull(&client);
+    let received = client.balance(&contract) - before;
+    if received <= 0 {
+        panic_with_error!(env, Error::NothingReceived);
+    }
+    received
+}
+
+// Kalıcı depolamadaki talep edilebilir bakiyeyi okur; bakiye yoksa (hiç yatırılmamış
+// veya talep edilmişse) `None` döner. Okunan kaydın ömrü de uzatılır.
+pub(crate) fn read_balance(env: &Env, balance_id: u64) -> Option<ClaimableBalance> {
+    let key = DataKey::Balance(balance_id);
+    let balance = env.storage().persistent().get(&key);
+    if balance.is_some() {
+        env.storage().persistent().extend_ttl(
+            &key,
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        );
     }
+    balance
+}
+
+// `read_balance` gibi bakiyeyi okur, ancak bakiye yoksa `Error::BalanceNotFound` ile paniklet.
+pub(crate) fn load_balance(env: &Env, balance_id: u64) -> ClaimableBalance {
+    read_balance(env, balance_id)
+        .unwrap_or_else(|| panic_with_error!(env, Error::BalanceNotFound))
+}
+
+// Talep edilebilir bakiyeyi kalıcı depolamaya yazar ve kaydın ömrünü uzatır.
+pub(crate) fn write_balance(env: &Env, balance_id: u64, balance: &ClaimableBalance) {
+    let key = DataKey::Balance(balance_id);
+    env.storage().persistent().set(&key, balance);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    // Bakiye bir oy kilidiyse sahibinin oy gücünü güncelle.
+    voting::sync_balance(env, balance_id, balance);
 }
 
-// Bu yardımcı fonksiyon, kontratın daha önce başlatılıp başlatılmadığını kontrol eder.
-// `env`: Kontrat ortamı.
-// `-> bool`: Kontrat başlatılmışsa `true`, değilse `false` döner.
-fn is_initialized(env: &Env) -> bool {
-    // Kontratın örnek depolamasında `DataKey::Init` anahtarının var olup olmadığını kontrol eder.
-    env.storage().instance().has(&DataKey::Init)
+// Talep edilen veya geri alınan bakiyenin kaydını ve bakiyeye bağlı ek kayıtları siler.
+// Başka bir bakiye bu bakiyeye bağlıysa, bakiyenin nasıl kapandığı (`outcome`) saklanır.
+pub(crate) fn remove_balance(env: &Env, balance_id: u64, outcome: BalanceOutcome) {
+    conditions::record_outcome(env, balance_id, outcome);
+    voting::remove_lock(env, balance_id);
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::Balance(balance_id));
+    storage.remove(&DataKey::InactivityWindow(balance_id));
+    storage.remove(&DataKey::Tiers(balance_id));
+    storage.remove(&DataKey::Condition(balance_id));
+    storage.remove(&DataKey::ExtendPolicy(balance_id));
+    storage.remove(&DataKey::Receipt(balance_id));
 }
 
 // `test` adında bir modül olduğunu bildirir.
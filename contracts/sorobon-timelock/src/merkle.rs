@@ -0,0 +1,242 @@
+// Merkle kökü ile tanımlanan büyük dağıtımlar (airdrop, bordro).
+//
+// Talepçi listesi zincire yazılmaz; sadece `(sıra, adres, miktar)` yapraklarından oluşan
+// ağacın kökü saklanır. Her talepçi kendi yaprağını ve Merkle ispatını (proof) sunarak
+// payını alır. Talep edilen yapraklar bir bit haritasında (bitmap) işaretlenir.
+//
+// Yaprak: `sha256(xdr((sıra, adres, miktar)))`. Ara düğüm: `sha256(küçük || büyük)`;
+// çocuklar birleştirilmeden önce sıralandığı için ispatta yön bilgisi gerekmez.
+use soroban_sdk::{
+    contractimpl, contracttype, panic_with_error, token, xdr::ToXdr, Address, Bytes, BytesN,
+    Env, Vec,
+};
+
+use crate::{
+    check_time_bound, events, fee, is_expired, next_balance_id, pause, receive_tokens, registry,
+    ClaimableBalanceContract, ClaimableBalanceContractArgs, ClaimableBalanceContractClient,
+    DataKey, Error, TimeBound, TimeBoundKind, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+// Bit haritasının her kaydında tutulan yaprak sayısı.
+const LEAVES_PER_WORD: u32 = 64;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MerkleDistribution {
+    // Dağıtılan token'ın adresi.
+    pub token: Address,
+    // Henüz talep edilmemiş token miktarı.
+    pub remaining: i128,
+    // `(sıra, adres, miktar)` yapraklarından oluşan ağacın kökü.
+    pub root: BytesN<32>,
+    // Ağaçtaki yaprak sayısı; geçerli sıralar `0..leaf_count` aralığındadır.
+    pub leaf_count: u32,
+    // Dağıtımın ne zaman talep edilebileceğini belirleyen zaman sınırı.
+    pub time_bound: TimeBound,
+    // Token'ları yatıran adres; süre dolunca kalanı geri alabilir.
+    pub depositor: Address,
+}
+
+// Bir yaprağın özetini (hash) hesaplar.
+pub(crate) fn leaf_hash(env: &Env, index: u32, claimant: &Address, amount: i128) -> BytesN<32> {
+    let leaf = (index, claimant.clone(), amount).to_xdr(env);
+    env.crypto().sha256(&leaf).to_bytes()
+}
+
+// İki düğümü sıralayarak birleştirir ve üst düğümün özetini hesaplar.
+pub(crate) fn node_hash(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    let mut node = Bytes::from(low.clone());
+    node.append(&Bytes::from(high.clone()));
+    env.crypto().sha256(&node).to_bytes()
+}
+
+// `leaf`'ten başlayarak ispattaki kardeş düğümlerle kökü yeniden hesaplar ve `root` ile karşılaştırır.
+fn verify(env: &Env, root: &BytesN<32>, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    let mut computed = leaf;
+    for sibling in proof.iter() {
+        computed = node_hash(env, &computed, &sibling);
+    }
+    computed == *root
+}
+
+fn read_distribution(env: &Env, distribution_id: u64) -> MerkleDistribution {
+    let key = DataKey::Distribution(distribution_id);
+    let distribution = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, Error::BalanceNotFound));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    distribution
+}
+
+fn write_distribution(env: &Env, distribution_id: u64, distribution: &MerkleDistribution) {
+    let key = DataKey::Distribution(distribution_id);
+    env.storage().persistent().set(&key, distribution);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// Yaprağın bulunduğu bit haritası kaydının anahtarını ve kayıt içindeki bit maskesini döndürür.
+fn claimed_slot(distribution_id: u64, index: u32) -> (DataKey, u64) {
+    (
+        DataKey::ClaimedLeaves(distribution_id, index / LEAVES_PER_WORD),
+        1 << (index % LEAVES_PER_WORD),
+    )
+}
+
+fn read_claimed_word(env: &Env, key: &DataKey) -> u64 {
+    env.storage().persistent().get(key).unwrap_or(0)
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `from`'dan `amount` kadar token yatırarak Merkle köküyle tanımlanan bir dağıtım oluşturur.
+    // Dağıtım, bakiyelerle aynı kimlik sayacından bir kimlik alır ve bu kimlik döndürülür.
+    // Yapraklardaki miktarların toplamı, kaydedilen net miktarı aşmamalıdır; aşan talepler reddedilir.
+    // Zaman sınırı 'Before' türünde olmalıdır: talep edilmeyen paylar ancak sınır dolunca geri
+    // alınabildiği için, 'After' sınırlı bir dağıtımın kalanı kontratta sonsuza dek kilitli kalırdı.
+    pub fn deposit_merkle(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        root: BytesN<32>,
+        leaf_count: u32,
+        time_bound: TimeBound,
+    ) -> u64 {
+        pause::ensure_not_paused(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if leaf_count == 0 {
+            panic_with_error!(&env, Error::NoClaimants);
+        }
+        if time_bound.kind != TimeBoundKind::Before {
+            panic_with_error!(&env, Error::InvalidTimeBound);
+        }
+        from.require_auth();
+        registry::ensure_allowed(&env, &from);
+
+        let received = receive_tokens(&env, &token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &amount)
+        });
+        let distribution_id = next_balance_id(&env);
+        let (amount, fee) = fee::charge_deposit_fee(&env, &token, received);
+        events::deposit(&env, &from, distribution_id, &token, amount, fee);
+        write_distribution(
+            &env,
+            distribution_id,
+            &MerkleDistribution {
+                token,
+                remaining: amount,
+                root,
+                leaf_count,
+                time_bound,
+                depositor: from,
+            },
+        );
+        distribution_id
+    }
+
+    // `index` sıradaki yaprağı `claimant` adına talep eder. `amount` yapraktaki miktardır;
+    // `proof`, yapraktan köke giden yoldaki kardeş düğümlerdir. Talep ücreti kesilir.
+    pub fn claim_merkle(
+        env: Env,
+        distribution_id: u64,
+        index: u32,
+        claimant: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) {
+        pause::ensure_not_paused(&env);
+        claimant.require_auth();
+        registry::ensure_allowed(&env, &claimant);
+        let mut distribution = read_distribution(&env, distribution_id);
+
+        if !check_time_bound(&env, &distribution.time_bound) {
+            panic!("time predicate is not fulfilled"); // "zaman koşulu karşılanmadı"
+        }
+        if index >= distribution.leaf_count {
+            panic_with_error!(&env, Error::InvalidProof);
+        }
+        let (key, mask) = claimed_slot(distribution_id, index);
+        let word = read_claimed_word(&env, &key);
+        if word & mask != 0 {
+            panic_with_error!(&env, Error::AlreadyClaimed);
+        }
+        let leaf = leaf_hash(&env, index, &claimant, amount);
+        if !verify(&env, &distribution.root, leaf, &proof) {
+            panic_with_error!(&env, Error::InvalidProof);
+        }
+        if amount > distribution.remaining {
+            panic_with_error!(&env, Error::AmountExceedsBalance);
+        }
+
+        env.storage().persistent().set(&key, &(word | mask));
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        distribution.remaining -= amount;
+        write_distribution(&env, distribution_id, &distribution);
+
+        let (paid, fee) = fee::charge_claim_fee(&env, &distribution.token, amount);
+        token::Client::new(&env, &distribution.token).transfer(
+            &env.current_contract_address(),
+            &claimant,
+            &paid,
+        );
+        events::claim(&env, &claimant, distribution_id, &distribution.token, paid, fee);
+    }
+
+    // `index` sıradaki yaprağın talep edilip edilmediğini döndürür.
+    pub fn is_leaf_claimed(env: Env, distribution_id: u64, index: u32) -> bool {
+        let (key, mask) = claimed_slot(distribution_id, index);
+        read_claimed_word(&env, &key) & mask != 0
+    }
+
+    // Verilen kimlikteki dağıtımı döndürür; yoksa `None` döner.
+    pub fn get_distribution(env: Env, distribution_id: u64) -> Option<MerkleDistribution> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Distribution(distribution_id))
+    }
+
+    // Zaman sınırı dolduktan sonra talep edilmemiş kalan token'ları yatıran adrese geri öder.
+    // `reclaim` ile aynı kurallar geçerlidir; dağıtımlar hep 'Before' sınırlı olduğu için her
+    // dağıtımın kalanı sınır dolduktan sonra geri alınabilir.
+    pub fn reclaim_merkle(env: Env, distribution_id: u64) {
+        if !pause::read_policy(&env).allow_reclaim {
+            pause::ensure_not_paused(&env);
+        }
+        let distribution = read_distribution(&env, distribution_id);
+        distribution.depositor.require_auth();
+        registry::ensure_allowed(&env, &distribution.depositor);
+        if !is_expired(&env, &distribution.time_bound) {
+            panic!("balance can still be claimed"); // "bakiye hâlâ talep edilebilir"
+        }
+
+        if distribution.remaining > 0 {
+            token::Client::new(&env, &distribution.token).transfer(
+                &env.current_contract_address(),
+                &distribution.depositor,
+                &distribution.remaining,
+            );
+        }
+        events::reclaim(
+            &env,
+            &distribution.depositor,
+            distribution_id,
+            &distribution.token,
+            distribution.remaining,
+        );
+        // Bit haritası kayıtları kendi ömürleri dolunca silinir; dağıtım olmadan bir işe yaramazlar.
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Distribution(distribution_id));
+    }
+}
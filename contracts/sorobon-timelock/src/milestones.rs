@@ -0,0 +1,254 @@
+// Yatıran onayıyla aşama aşama (milestone) ödenen bakiyeler.
+//
+// Hibe ve serbest çalışma ödemeleri için: her aşamanın kendi miktarı vardır ve yatıran
+// `approve_milestone` ile onayladığında lehtar tarafından talep edilebilir hale gelir.
+// Bir aşamaya otomatik onay zamanı verilmişse, yatıran o zamana kadar sessiz kalırsa
+// aşama onaylanmış sayılır. Yatıran, henüz onaylanmamış bir aşamayı iptal ederek
+// miktarını geri alabilir.
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, token, Address, Env, Vec};
+
+use crate::{
+    events, fee, next_balance_id, pause, receive_tokens, registry, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, Error,
+    BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+// Bir bakiyedeki en yüksek aşama sayısı. Talep sırasında tüm aşamalar tek tek tarandığından
+// liste sınırlandırılır.
+pub const MAX_MILESTONES: u32 = 20;
+
+// Yatırma sırasında verilen aşama koşulları.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MilestoneTerms {
+    // Aşama tamamlandığında ödenecek miktar.
+    pub amount: i128,
+    // Verilmişse, yatıran bu zamana kadar onaylamaz veya iptal etmezse aşama onaylanmış sayılır.
+    pub auto_approve_at: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum MilestoneStatus {
+    // Onay bekliyor.
+    Pending,
+    // Yatıran onayladı; lehtar talep edebilir.
+    Approved,
+    // Lehtara ödendi.
+    Claimed,
+    // Yatıran iptal etti ve miktarı geri aldı.
+    Cancelled,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Milestone {
+    pub terms: MilestoneTerms,
+    pub status: MilestoneStatus,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MilestoneGrant {
+    // Token'ları yatıran ve aşamaları onaylayan adres.
+    pub depositor: Address,
+    // Onaylanan aşamaları talep eden lehtar.
+    pub beneficiary: Address,
+    // Ödenen token'ın adresi.
+    pub token: Address,
+    // Aşamalar, verildikleri sırayla.
+    pub milestones: Vec<Milestone>,
+    // Havuzda kalan (net) token miktarı.
+    pub remaining: i128,
+}
+
+// Aşamanın `now` anında lehtar tarafından talep edilebilir olup olmadığını döndürür.
+fn is_claimable(milestone: &Milestone, now: u64) -> bool {
+    match milestone.status {
+        MilestoneStatus::Approved => true,
+        MilestoneStatus::Pending => milestone
+            .terms
+            .auto_approve_at
+            .is_some_and(|deadline| now >= deadline),
+        MilestoneStatus::Claimed | MilestoneStatus::Cancelled => false,
+    }
+}
+
+fn read_grant(env: &Env, grant_id: u64) -> MilestoneGrant {
+    let key = DataKey::Milestones(grant_id);
+    let grant = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, Error::BalanceNotFound));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    grant
+}
+
+// Aşamalar hâlâ açıksa kaydı yazar; tüm aşamalar ödendiyse veya iptal edildiyse siler.
+fn write_grant(env: &Env, grant_id: u64, grant: &MilestoneGrant) {
+    let key = DataKey::Milestones(grant_id);
+    let open = grant.milestones.iter().any(|milestone| {
+        matches!(
+            milestone.status,
+            MilestoneStatus::Pending | MilestoneStatus::Approved
+        )
+    });
+    if !open {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, grant);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// `index` sıradaki aşamayı döndürür; yoksa `Error::InvalidMilestone` ile paniklet.
+fn milestone_at(env: &Env, grant: &MilestoneGrant, index: u32) -> Milestone {
+    grant
+        .milestones
+        .get(index)
+        .unwrap_or_else(|| panic_with_error!(env, Error::InvalidMilestone))
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `from`'dan aşama miktarlarının toplamı kadar token yatırarak `beneficiary` için aşamalı
+    // bir bakiye oluşturur ve kimliğini döndürür. Yatırma ücreti kesildiyse havuz toplamdan
+    // azdır; bu durumda ödemeler havuzda kalanla sınırlıdır.
+    pub fn deposit_milestones(
+        env: Env,
+        from: Address,
+        beneficiary: Address,
+        token: Address,
+        terms: Vec<MilestoneTerms>,
+    ) -> u64 {
+        pause::ensure_not_paused(&env);
+        if terms.is_empty() || terms.len() > MAX_MILESTONES {
+            panic_with_error!(&env, Error::InvalidSchedule);
+        }
+        let mut total: i128 = 0;
+        let mut milestones = Vec::new(&env);
+        for milestone in terms.iter() {
+            if milestone.amount <= 0 {
+                panic_with_error!(&env, Error::InvalidAmount);
+            }
+            total = total
+                .checked_add(milestone.amount)
+                .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidAmount));
+            milestones.push_back(Milestone {
+                terms: milestone,
+                status: MilestoneStatus::Pending,
+            });
+        }
+        from.require_auth();
+        registry::ensure_allowed(&env, &from);
+
+        let received = receive_tokens(&env, &token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &total)
+        });
+        let grant_id = next_balance_id(&env);
+        let (remaining, fee) = fee::charge_deposit_fee(&env, &token, received);
+        events::deposit(&env, &from, grant_id, &token, remaining, fee);
+        write_grant(
+            &env,
+            grant_id,
+            &MilestoneGrant {
+                depositor: from,
+                beneficiary,
+                token,
+                milestones,
+                remaining,
+            },
+        );
+        grant_id
+    }
+
+    // Yatıranın `index` sıradaki aşamayı onaylamasını sağlar.
+    pub fn approve_milestone(env: Env, grant_id: u64, index: u32) {
+        let mut grant = read_grant(&env, grant_id);
+        grant.depositor.require_auth();
+        let mut milestone = milestone_at(&env, &grant, index);
+        if milestone.status != MilestoneStatus::Pending {
+            panic_with_error!(&env, Error::InvalidMilestone);
+        }
+        milestone.status = MilestoneStatus::Approved;
+        grant.milestones.set(index, milestone);
+        write_grant(&env, grant_id, &grant);
+        events::milestone_approved(&env, &grant.depositor, grant_id, index);
+    }
+
+    // Yatıranın henüz onaylanmamış (otomatik onay zamanı da gelmemiş) bir aşamayı iptal edip
+    // miktarını geri almasını sağlar.
+    // Kontrat durdurulmuşken, `reclaim` gibi sadece durdurma kuralları izin veriyorsa çalışır.
+    pub fn cancel_milestone(env: Env, grant_id: u64, index: u32) {
+        if !pause::read_policy(&env).allow_reclaim {
+            pause::ensure_not_paused(&env);
+        }
+        let mut grant = read_grant(&env, grant_id);
+        grant.depositor.require_auth();
+        registry::ensure_allowed(&env, &grant.depositor);
+        let mut milestone = milestone_at(&env, &grant, index);
+        if milestone.status != MilestoneStatus::Pending
+            || is_claimable(&milestone, env.ledger().timestamp())
+        {
+            panic_with_error!(&env, Error::InvalidMilestone);
+        }
+
+        let amount = milestone.terms.amount.min(grant.remaining);
+        milestone.status = MilestoneStatus::Cancelled;
+        grant.milestones.set(index, milestone);
+        grant.remaining -= amount;
+        write_grant(&env, grant_id, &grant);
+        token::Client::new(&env, &grant.token).transfer(
+            &env.current_contract_address(),
+            &grant.depositor,
+            &amount,
+        );
+        events::reclaim(&env, &grant.depositor, grant_id, &grant.token, amount);
+    }
+
+    // Lehtarın onaylanmış (veya otomatik onaylanmış) tüm aşamaları tek seferde talep etmesini
+    // sağlar ve ödenen net miktarı döndürür. Talep ücreti kesilir.
+    pub fn claim_milestones(env: Env, grant_id: u64) -> i128 {
+        pause::ensure_not_paused(&env);
+        let mut grant = read_grant(&env, grant_id);
+        grant.beneficiary.require_auth();
+        registry::ensure_allowed(&env, &grant.beneficiary);
+
+        let now = env.ledger().timestamp();
+        let mut amount: i128 = 0;
+        for (index, mut milestone) in grant.milestones.clone().iter().enumerate() {
+            if is_claimable(&milestone, now) {
+                amount += milestone.terms.amount;
+                milestone.status = MilestoneStatus::Claimed;
+                grant.milestones.set(index as u32, milestone);
+            }
+        }
+        if amount == 0 {
+            panic_with_error!(&env, Error::NothingToClaim);
+        }
+        let amount = amount.min(grant.remaining);
+        grant.remaining -= amount;
+        write_grant(&env, grant_id, &grant);
+
+        let (paid, fee) = fee::charge_claim_fee(&env, &grant.token, amount);
+        token::Client::new(&env, &grant.token).transfer(
+            &env.current_contract_address(),
+            &grant.beneficiary,
+            &paid,
+        );
+        events::claim(&env, &grant.beneficiary, grant_id, &grant.token, paid, fee);
+        paid
+    }
+
+    // Verilen kimlikteki aşamalı bakiyeyi döndürür; yoksa (veya tüm aşamalar kapandıysa) `None` döner.
+    pub fn get_milestones(env: Env, grant_id: u64) -> Option<MilestoneGrant> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Milestones(grant_id))
+    }
+}
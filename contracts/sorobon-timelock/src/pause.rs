@@ -0,0 +1,135 @@
+// Acil durum durdurma anahtarı (pause) ve koruyucu (guardian) rolü.
+//
+// Kabul edilen bir token'ın kötü niyetli olduğu ya da kontratta bir hata bulunduğu
+// anlaşılırsa, koruyucu `deposit` ve `claim` gibi fon hareket ettiren fonksiyonları
+// fonları kaybetmeden dondurabilir. Yönetici tarafından belirlenen en uzun süre,
+// koruyucunun fonları süresiz dondurmasını engeller: süren bir durdurma uzatılamaz ve
+// bir durdurma bittikten sonra en az aynı süre kadar yeni bir durdurma başlatılamaz.
+use soroban_sdk::{contractimpl, contracttype, Address, Env};
+
+use crate::{
+    admin, ClaimableBalanceContract, ClaimableBalanceContractArgs,
+    ClaimableBalanceContractClient, DataKey,
+};
+
+// Kurallar ayarlanmamışsa bir durdurmanın en fazla sürebileceği süre (3 gün).
+pub const DEFAULT_MAX_PAUSE_DURATION: u64 = 3 * 24 * 60 * 60;
+// Yöneticinin izin verebileceği en uzun durdurma süresi (30 gün).
+pub const MAX_PAUSE_DURATION: u64 = 30 * 24 * 60 * 60;
+
+// Durdurma kuralları. Yönetici tarafından `set_pause_policy` ile değiştirilir.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PausePolicy {
+    // Bir durdurmanın en fazla kaç saniye sürebileceği. Sıfırdan büyük olmalı ve
+    // `MAX_PAUSE_DURATION`'ı aşmamalıdır. Bir durdurma bittikten sonra bu süre dolmadan
+    // yeniden durdurulamaz.
+    pub max_duration: u64,
+    // Kontrat durdurulmuşken yatıranların süresi dolan bakiyeleri geri alıp alamayacağı.
+    pub allow_reclaim: bool,
+}
+
+// Kayıtlı durdurma kurallarını okur. Hiç ayarlanmamışsa bir durdurma en fazla
+// `DEFAULT_MAX_PAUSE_DURATION` sürer ve durdurma sırasında geri alma kapalıdır.
+pub(crate) fn read_policy(env: &Env) -> PausePolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::PausePolicy)
+        .unwrap_or(PausePolicy {
+            max_duration: DEFAULT_MAX_PAUSE_DURATION,
+            allow_reclaim: false,
+        })
+}
+
+// Kontratın şu anda durdurulmuş olup olmadığını kontrol eder. Süresi dolan bir durdurma
+// kendiliğinden kalkmış sayılır.
+pub(crate) fn is_paused(env: &Env) -> bool {
+    match env.storage().instance().get::<_, u64>(&DataKey::Paused) {
+        None => false,
+        Some(until) => env.ledger().timestamp() < until,
+    }
+}
+
+// Kontrat durdurulmuşsa paniklet. Fon hareket ettiren her fonksiyonun başında çağrılır.
+pub(crate) fn ensure_not_paused(env: &Env) {
+    if is_paused(env) {
+        panic!("contract is paused"); // "kontrat durduruldu"
+    }
+}
+
+// Kayıtlı koruyucunun bu çağrıyı yetkilendirdiğinden emin olur.
+fn require_guardian(env: &Env) {
+    let guardian: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Guardian)
+        .unwrap_or_else(|| panic!("guardian is not set")); // "koruyucu atanmamış"
+    guardian.require_auth();
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // Koruyucu adresi atar veya değiştirir. Sadece yönetici çağırabilir.
+    pub fn set_guardian(env: Env, guardian: Address) {
+        admin::require_admin(&env);
+        env.storage().instance().set(&DataKey::Guardian, &guardian);
+    }
+
+    // Durdurma kurallarını değiştirir. Sadece yönetici çağırabilir. Koruyucu fonları süresiz
+    // donduramasın diye en uzun süre sıfır olamaz ve `MAX_PAUSE_DURATION`'ı aşamaz.
+    pub fn set_pause_policy(env: Env, policy: PausePolicy) {
+        admin::require_admin(&env);
+        if policy.max_duration == 0 || policy.max_duration > MAX_PAUSE_DURATION {
+            panic!("invalid pause duration"); // "geçersiz durdurma süresi"
+        }
+        env.storage().instance().set(&DataKey::PausePolicy, &policy);
+    }
+
+    // Kontratı durdurur. Sadece koruyucu çağırabilir.
+    // `until`: Durdurmanın kendiliğinden kalkacağı zaman damgası. Verilmezse durdurma
+    // şimdiden itibaren kurallardaki en uzun süre sonunda kalkar.
+    pub fn pause(env: Env, until: Option<u64>) {
+        require_guardian(&env);
+
+        let now = env.ledger().timestamp();
+        // Süren bir durdurmanın üzerine yenisi yazılarak en uzun süre aşılamaz.
+        if is_paused(&env) {
+            panic!("contract is already paused"); // "kontrat zaten durdurulmuş"
+        }
+        let policy = read_policy(&env);
+        // Arka arkaya durdurmalarla fonlar süresiz dondurulamasın diye, önceki durdurmanın
+        // bitişinden itibaren en uzun süre kadar beklenir.
+        let last_end = env.storage().instance().get::<_, u64>(&DataKey::Paused);
+        if let Some(last_end) = last_end {
+            if now < last_end.saturating_add(policy.max_duration) {
+                panic!("pause cooldown has not elapsed"); // "durdurma bekleme süresi dolmadı"
+            }
+        }
+        if let Some(until) = until {
+            if until <= now {
+                panic!("pause must end in the future"); // "durdurma gelecekte bitmeli"
+            }
+        }
+        let max_until = now.saturating_add(policy.max_duration);
+        let until = until.unwrap_or(max_until);
+        if until > max_until {
+            panic!("pause exceeds maximum duration"); // "durdurma en uzun süreyi aşıyor"
+        }
+        env.storage().instance().set(&DataKey::Paused, &until);
+    }
+
+    // Durdurmayı hemen kaldırır. Sadece koruyucu çağırabilir.
+    // Bitiş zamanı şimdi olarak kaydedilir; yeni bir durdurma için bekleme süresi buradan başlar.
+    pub fn unpause(env: Env) {
+        require_guardian(&env);
+        if is_paused(&env) {
+            let now = env.ledger().timestamp();
+            env.storage().instance().set(&DataKey::Paused, &now);
+        }
+    }
+
+    // Kontratın şu anda durdurulmuş olup olmadığını döndürür.
+    pub fn is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+}
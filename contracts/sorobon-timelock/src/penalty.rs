@@ -0,0 +1,120 @@
+// Cezalı erken talep.
+//
+// 'After' türündeki bir bakiyenin talepçisi, kilit açılmadan önce bir ceza ödeyerek bakiyeyi
+// talep edebilir. Ceza, kilidin açılmasına kalan süreyle doğrusal olarak azalır: açılmaya
+// `ramp` saniye veya daha fazla kaldığında en yüksek cezadır (`max_penalty_bps`), açılma
+// anında sıfırdır. Kesilen ceza, yönetici tarafından belirlenen lehtara (ör. hazine) gönderilir.
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, token, Address, Env};
+
+use crate::fee::{compute_fee, BPS_DENOMINATOR};
+use crate::{
+    admin, check_claimant, events, load_balance, pause, pay_claim, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, Error, TimeBoundKind,
+};
+
+// Erken talep kuralları. Örnek (instance) depolamada tutulur.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PenaltyConfig {
+    // Kesilen cezaların gönderileceği adres (ör. hazine veya kalan kilit sahiplerine dağıtan bir kontrat).
+    pub beneficiary: Address,
+    // Açılmaya en az `ramp` saniye kaldığında kesilen ceza (baz puan), en fazla 10.000.
+    pub max_penalty_bps: u32,
+    // Cezanın en yüksek değerden sıfıra indiği süre (saniye).
+    pub ramp: u64,
+}
+
+fn read_config(env: &Env) -> Option<PenaltyConfig> {
+    env.storage().instance().get(&DataKey::PenaltyConfig)
+}
+
+// Açılmaya `remaining` saniye kalan `amount` miktarındaki bir bakiyenin erken talep cezasını
+// hesaplar: `⌊⌊amount * max_penalty_bps / 10.000⌋ * min(remaining, ramp) / ramp⌋`.
+//
+// `streamed_amount`'taki gibi, en yüksek ceza `ramp` ile bölünüp kalanıyla ayrı çarpılır;
+// böylece ara sonuçlar taşmaz ve sonuç tam olarak aşağı yuvarlanır.
+pub(crate) fn compute_penalty(config: &PenaltyConfig, amount: i128, remaining: u64) -> i128 {
+    let max_penalty = compute_fee(amount, config.max_penalty_bps);
+    let remaining = remaining.min(config.ramp);
+    let ramp = config.ramp as i128;
+    let rate = max_penalty / ramp;
+    let remainder = (max_penalty % ramp) as u128;
+    rate * remaining as i128 + (remainder * remaining as u128 / config.ramp as u128) as i128
+}
+
+// Erken talep kurallarının geçerli olduğunu kontrol eder.
+fn validate(config: &PenaltyConfig) {
+    if config.max_penalty_bps as i128 > BPS_DENOMINATOR || config.ramp == 0 {
+        panic!("invalid penalty config"); // "geçersiz ceza kuralları"
+    }
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // Erken talep kurallarını atar; `None` erken talebi kapatır. Sadece yönetici çağırabilir.
+    pub fn set_penalty_config(env: Env, config: Option<PenaltyConfig>) {
+        admin::require_admin(&env);
+        match config {
+            Some(config) => {
+                validate(&config);
+                env.storage()
+                    .instance()
+                    .set(&DataKey::PenaltyConfig, &config);
+            }
+            None => env.storage().instance().remove(&DataKey::PenaltyConfig),
+        }
+    }
+
+    // Yürürlükteki erken talep kurallarını döndürür.
+    pub fn penalty_config(env: Env) -> Option<PenaltyConfig> {
+        read_config(&env)
+    }
+
+    // Bakiye şu anda erken talep edilirse kesilecek cezayı döndürür.
+    pub fn early_claim_penalty(env: Env, balance_id: u64) -> i128 {
+        let config = read_config(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::EarlyClaimNotAvailable));
+        let balance = load_balance(&env, balance_id);
+        let remaining = balance
+            .time_bound
+            .timestamp
+            .saturating_sub(env.ledger().timestamp());
+        compute_penalty(&config, balance.amount, remaining)
+    }
+
+    // 'After' türündeki bir bakiyeyi kilit açılmadan önce talep eder. Ceza lehtara gönderilir,
+    // kalan miktar talep ücreti kesilerek talepçiye ödenir; ödenen net miktar döndürülür.
+    // Talepçi listesi, kayıt, kademe ve koşul kontrolleri `claim` ile aynıdır. Miras kilitleri
+    // erken talep edilemez.
+    pub fn early_claim(env: Env, balance_id: u64, claimant: Address) -> i128 {
+        pause::ensure_not_paused(&env);
+        claimant.require_auth();
+        let config = read_config(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::EarlyClaimNotAvailable));
+        let balance = load_balance(&env, balance_id);
+        if balance.time_bound.kind != TimeBoundKind::After
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::InactivityWindow(balance_id))
+        {
+            panic_with_error!(&env, Error::EarlyClaimNotAvailable);
+        }
+        check_claimant(&env, balance_id, &balance, &claimant);
+
+        let remaining = balance
+            .time_bound
+            .timestamp
+            .saturating_sub(env.ledger().timestamp());
+        let penalty = compute_penalty(&config, balance.amount, remaining);
+        if penalty > 0 {
+            token::Client::new(&env, &balance.token).transfer(
+                &env.current_contract_address(),
+                &config.beneficiary,
+                &penalty,
+            );
+        }
+        events::penalty(&env, &claimant, balance_id, &balance.token, penalty);
+        pay_claim(&env, balance_id, &balance, &claimant, balance.amount - penalty)
+    }
+}
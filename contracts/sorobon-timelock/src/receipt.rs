@@ -0,0 +1,154 @@
+// Talep pozisyonlarının makbuz (receipt) token'larıyla token'laştırılması.
+//
+// Bir talepçi, bakiyenin talep hakkını transfer edilebilir bir token'a dönüştürebilir. Kontrat
+// bu bakiye için, yöneticinin yüklediği Wasm'dan SEP-41 uyumlu bir makbuz token'ı deploy eder
+// (`receipt-token` kontratı) ve bakiyedeki her token için talepçiye bir makbuz basar. Bundan
+// sonra bakiye `claim` ile değil, zaman sınırı karşılandığında makbuz sahiplerinin makbuzlarını
+// yakarak karşılığındaki token'ları aldığı `redeem` ile talep edilir.
+//
+// Makbuz token'ının adresi bakiyenin kimliğinden türetilen sabit bir tuzla (salt) belirlenir.
+use soroban_sdk::{
+    contractclient, contractimpl, panic_with_error, symbol_short, token, Address, BytesN, Env,
+    String,
+};
+
+use crate::{
+    admin, check_claimant, check_time_bound, events, fee, load_balance, pause, registry,
+    remove_balance, voting, write_balance, BalanceOutcome, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, Error,
+    BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+// Makbuz token'ının standart token arayüzü dışındaki, sadece yöneticinin çağırabildiği fonksiyonu.
+#[contractclient(name = "ReceiptClient")]
+pub trait Receipt {
+    fn mint(env: Env, to: Address, amount: i128);
+}
+
+// Bakiye token'laştırılmışsa makbuz token'ının adresini döndürür.
+pub(crate) fn read_receipt(env: &Env, balance_id: u64) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::Receipt(balance_id))
+}
+
+// Bakiye token'laştırılmışsa `Error::BalanceTokenized` ile paniklet. Talep hakkı makbuz
+// sahiplerine geçtiği için talepçiler bakiyeyi talep edemez, uzatamaz ve bakiyeye ekleme yapılamaz.
+pub(crate) fn ensure_not_tokenized(env: &Env, balance_id: u64) {
+    if env.storage().persistent().has(&DataKey::Receipt(balance_id)) {
+        panic_with_error!(env, Error::BalanceTokenized);
+    }
+}
+
+// Bakiyenin makbuz token'ı için deploy tuzu: bakiye kimliği, büyük uçlu (big-endian) olarak sona yazılır.
+fn receipt_salt(env: &Env, balance_id: u64) -> BytesN<32> {
+    let mut salt = [0; 32];
+    salt[24..].copy_from_slice(&balance_id.to_be_bytes());
+    BytesN::from_array(env, &salt)
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // Makbuz token'larının deploy edileceği, önceden yüklenmiş Wasm'ın özetini (hash) ayarlar.
+    // Sadece yönetici çağırabilir.
+    pub fn set_receipt_wasm(env: Env, wasm_hash: BytesN<32>) {
+        admin::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::ReceiptWasm, &wasm_hash);
+    }
+
+    // `claimant`'ın bakiyenin talep hakkını token'laştırmasını sağlar: bir makbuz token'ı deploy
+    // edilir ve `claimant`'a bakiyedeki miktar kadar makbuz basılır. Makbuz token'ının adresini döndürür.
+    // Kademeli, koşullu bakiyeler ve miras kilitleri token'laştırılamaz.
+    pub fn tokenize(env: Env, balance_id: u64, claimant: Address) -> Address {
+        pause::ensure_not_paused(&env);
+        claimant.require_auth();
+        let balance = load_balance(&env, balance_id);
+        let storage = env.storage().persistent();
+        if balance.claimants.is_empty()
+            || storage.has(&DataKey::Tiers(balance_id))
+            || storage.has(&DataKey::Condition(balance_id))
+            || storage.has(&DataKey::InactivityWindow(balance_id))
+        {
+            panic_with_error!(&env, Error::NotTokenizable);
+        }
+        // Bakiye zaten token'laştırıldıysa `check_claimant` `Error::BalanceTokenized` ile paniklet.
+        check_claimant(&env, balance_id, &balance, &claimant);
+        let wasm_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReceiptWasm)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotTokenizable));
+
+        let decimals = token::Client::new(&env, &balance.token).decimals();
+        let receipt = env
+            .deployer()
+            .with_current_contract(receipt_salt(&env, balance_id))
+            .deploy_v2(
+                wasm_hash,
+                (
+                    env.current_contract_address(),
+                    decimals,
+                    String::from_str(&env, "Timelock Receipt"),
+                    String::from_str(&env, "RCPT"),
+                ),
+            );
+        ReceiptClient::new(&env, &receipt).mint(&claimant, &balance.amount);
+
+        let key = DataKey::Receipt(balance_id);
+        env.storage().persistent().set(&key, &receipt);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        // Talep hakkı artık yatırana ait olmadığı için bakiye oy gücü vermez.
+        voting::remove_lock(&env, balance_id);
+        env.events().publish(
+            (symbol_short!("tokenize"), claimant),
+            (balance_id, receipt.clone()),
+        );
+        receipt
+    }
+
+    // `holder`'ın, zaman sınırı karşılandığında `amount` kadar makbuzu yakarak karşılığındaki
+    // token'ları almasını sağlar. Talep ücreti kesilir; ödenen net miktar döndürülür. Bütün
+    // makbuzlar kullanıldığında bakiye kapanır.
+    pub fn redeem(env: Env, balance_id: u64, holder: Address, amount: i128) -> i128 {
+        pause::ensure_not_paused(&env);
+        holder.require_auth();
+        let mut balance = load_balance(&env, balance_id);
+        let receipt = read_receipt(&env, balance_id)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotTokenizable));
+        if !check_time_bound(&env, &balance.time_bound) {
+            panic!("time predicate is not fulfilled"); // "zaman koşulu karşılanmadı"
+        }
+        registry::ensure_allowed(&env, &holder);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if amount > balance.amount {
+            panic_with_error!(&env, Error::AmountExceedsBalance);
+        }
+
+        // Makbuzları yak; `holder`'ın yetkilendirmesi bu alt çağrıyı da kapsar.
+        token::Client::new(&env, &receipt).burn(&holder, &amount);
+        balance.amount -= amount;
+        if balance.amount == 0 {
+            remove_balance(&env, balance_id, BalanceOutcome::Claimed);
+        } else {
+            write_balance(&env, balance_id, &balance);
+        }
+
+        let (paid, fee) = fee::charge_claim_fee(&env, &balance.token, amount);
+        token::Client::new(&env, &balance.token).transfer(
+            &env.current_contract_address(),
+            &holder,
+            &paid,
+        );
+        events::claim(&env, &holder, balance_id, &balance.token, paid, fee);
+        paid
+    }
+
+    // Bakiye token'laştırılmışsa makbuz token'ının adresini döndürür; değilse `None` döner.
+    pub fn get_receipt(env: Env, balance_id: u64) -> Option<Address> {
+        read_receipt(&env, balance_id)
+    }
+}
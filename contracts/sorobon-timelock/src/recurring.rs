@@ -0,0 +1,169 @@
+// Kilitli bir havuzdan dönemsel (periyodik) ödemeler.
+//
+// Yatıran, `count` dönem boyunca her `period` saniyede bir `amount_per_period` token'ın
+// talepçiye açıldığı bir ödeme planı oluşturur. `i`. dönemin payı `start + i * period`
+// anında açılır. Talep edilmeyen dönemler birikir ve tek bir talepte birlikte ödenir.
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, token, Address, Env};
+
+use crate::{
+    events, fee, next_balance_id, pause, receive_tokens, registry, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, Error,
+    BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+// Ödeme planının zamanlaması ve dönem başına miktarı.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RecurringSchedule {
+    // Her dönem açılan miktar.
+    pub amount_per_period: i128,
+    // Bir dönemin uzunluğu (saniye).
+    pub period: u64,
+    // Planın başladığı Unix zaman damgası.
+    pub start: u64,
+    // Toplam dönem sayısı.
+    pub count: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RecurringPayout {
+    // Token'ları yatıran adres.
+    pub depositor: Address,
+    // Dönemsel ödemeleri alan adres.
+    pub claimant: Address,
+    // Ödenen token'ın adresi.
+    pub token: Address,
+    // Ödeme planı.
+    pub schedule: RecurringSchedule,
+    // Şimdiye kadar talep edilmiş dönem sayısı.
+    pub claimed_periods: u32,
+    // Havuzda kalan (net) token miktarı.
+    pub remaining: i128,
+}
+
+// `now` anına kadar açılmış dönem sayısını hesaplar (en fazla `count`).
+pub(crate) fn elapsed_periods(schedule: &RecurringSchedule, now: u64) -> u32 {
+    if now < schedule.start {
+        return 0;
+    }
+    let elapsed = (now - schedule.start) / schedule.period;
+    elapsed.min(schedule.count as u64) as u32
+}
+
+fn read_payout(env: &Env, payout_id: u64) -> RecurringPayout {
+    let key = DataKey::Recurring(payout_id);
+    let payout = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, Error::BalanceNotFound));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    payout
+}
+
+fn write_payout(env: &Env, payout_id: u64, payout: &RecurringPayout) {
+    let key = DataKey::Recurring(payout_id);
+    env.storage().persistent().set(&key, payout);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `from`'dan `amount_per_period * count` kadar token yatırarak `claimant` için dönemsel
+    // bir ödeme planı oluşturur ve kimliğini döndürür. Yatırma ücreti kesildiyse havuz
+    // toplamdan azdır; bu durumda son dönemin ödemesi havuzda kalanla sınırlıdır.
+    pub fn deposit_recurring(
+        env: Env,
+        from: Address,
+        claimant: Address,
+        token: Address,
+        schedule: RecurringSchedule,
+    ) -> u64 {
+        pause::ensure_not_paused(&env);
+        if schedule.amount_per_period <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if schedule.period == 0 || schedule.count == 0 {
+            panic_with_error!(&env, Error::InvalidSchedule);
+        }
+        let total = schedule
+            .amount_per_period
+            .checked_mul(schedule.count as i128)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidAmount));
+        from.require_auth();
+        registry::ensure_allowed(&env, &from);
+
+        let received = receive_tokens(&env, &token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &total)
+        });
+        let payout_id = next_balance_id(&env);
+        let (remaining, fee) = fee::charge_deposit_fee(&env, &token, received);
+        events::deposit(&env, &from, payout_id, &token, remaining, fee);
+        write_payout(
+            &env,
+            payout_id,
+            &RecurringPayout {
+                depositor: from,
+                claimant,
+                token,
+                schedule,
+                claimed_periods: 0,
+                remaining,
+            },
+        );
+        payout_id
+    }
+
+    // Son talepten bu yana açılan tüm dönemlerin payını talepçiye öder ve ödenen net miktarı
+    // döndürür. Talep ücreti kesilir. Tüm dönemler talep edildiğinde kayıt silinir.
+    pub fn claim_recurring(env: Env, payout_id: u64) -> i128 {
+        pause::ensure_not_paused(&env);
+        let mut payout = read_payout(&env, payout_id);
+        payout.claimant.require_auth();
+        registry::ensure_allowed(&env, &payout.claimant);
+
+        let elapsed = elapsed_periods(&payout.schedule, env.ledger().timestamp());
+        let periods = elapsed - payout.claimed_periods;
+        if periods == 0 {
+            panic_with_error!(&env, Error::NothingToClaim);
+        }
+        // `periods <= count` olduğundan çarpım yatırılan toplamı aşmaz.
+        let amount = (payout.schedule.amount_per_period * periods as i128).min(payout.remaining);
+        payout.claimed_periods = elapsed;
+        payout.remaining -= amount;
+        if payout.claimed_periods == payout.schedule.count || payout.remaining == 0 {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Recurring(payout_id));
+        } else {
+            write_payout(&env, payout_id, &payout);
+        }
+
+        let (paid, fee) = fee::charge_claim_fee(&env, &payout.token, amount);
+        token::Client::new(&env, &payout.token).transfer(
+            &env.current_contract_address(),
+            &payout.claimant,
+            &paid,
+        );
+        events::claim(&env, &payout.claimant, payout_id, &payout.token, paid, fee);
+        paid
+    }
+
+    // Açılmış ancak henüz talep edilmemiş dönem sayısını döndürür.
+    pub fn claimable_periods(env: Env, payout_id: u64) -> u32 {
+        let payout = read_payout(&env, payout_id);
+        elapsed_periods(&payout.schedule, env.ledger().timestamp()) - payout.claimed_periods
+    }
+
+    // Verilen kimlikteki ödeme planını döndürür; yoksa `None` döner.
+    pub fn get_recurring(env: Env, payout_id: u64) -> Option<RecurringPayout> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Recurring(payout_id))
+    }
+}
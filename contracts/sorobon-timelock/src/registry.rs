@@ -0,0 +1,50 @@
+// Dış izin listesi / KYC kaydı (registry) kontrolü.
+//
+// Yönetici bir kayıt kontratı atadıysa, token yatıran veya token alan her giriş noktası
+// (yatırma, ekleme, talep, geri alma, çekim, iade) ilgili adresi token'lar hareket etmeden
+// önce bu kontrata sorar. Kayıt atanmamışsa hiçbir kontrol yapılmaz.
+use soroban_sdk::{contractclient, contractimpl, panic_with_error, Address, Env};
+
+use crate::{
+    admin, ClaimableBalanceContract, ClaimableBalanceContractArgs,
+    ClaimableBalanceContractClient, DataKey, Error,
+};
+
+// Kayıt kontratlarının uyması gereken arayüz.
+#[contractclient(name = "RegistryClient")]
+pub trait Registry {
+    // Adresin fon yatırmasına ve talep etmesine izin veriliyorsa `true` döndürür.
+    fn is_allowed(env: Env, address: Address) -> bool;
+}
+
+fn read_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Registry)
+}
+
+// Kayıt atanmışsa `address`'in izinli olduğunu kontrol eder; değilse `Error::NotAllowed` ile paniklet.
+pub(crate) fn ensure_allowed(env: &Env, address: &Address) {
+    let Some(registry) = read_registry(env) else {
+        return;
+    };
+    if !RegistryClient::new(env, &registry).is_allowed(address) {
+        panic_with_error!(env, Error::NotAllowed);
+    }
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // Yatıran ve talepçilerin sorulacağı kayıt kontratını atar; `None` kontrolü kapatır.
+    // Sadece yönetici çağırabilir.
+    pub fn set_registry(env: Env, registry: Option<Address>) {
+        admin::require_admin(&env);
+        match registry {
+            Some(registry) => env.storage().instance().set(&DataKey::Registry, &registry),
+            None => env.storage().instance().remove(&DataKey::Registry),
+        }
+    }
+
+    // Atanmış kayıt kontratını döndürür; atanmamışsa `None` döner.
+    pub fn registry(env: Env) -> Option<Address> {
+        read_registry(&env)
+    }
+}
@@ -0,0 +1,199 @@
+// Saniye başına sabit hızla akan ödeme akışları (stream).
+//
+// Gönderen, yatırdığı token'ların `start` ve `stop` zamanları arasında alıcıya doğrusal
+// olarak aktığı bir akış oluşturur. Alıcı, biriken miktarı istediği zaman çekebilir
+// (`withdraw`). Gönderen akışı iptal ederse, birikmiş kısım alıcıya, kalanı gönderene ödenir.
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, token, Address, Env};
+
+use crate::{
+    events, fee, next_balance_id, pause, receive_tokens, registry, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, Error,
+    BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Stream {
+    // Akışı oluşturan ve token'ları yatıran adres.
+    pub sender: Address,
+    // Token'ların aktığı adres.
+    pub recipient: Address,
+    // Akan token'ın adresi.
+    pub token: Address,
+    // `start` ile `stop` arasında akacak toplam (net) miktar.
+    pub deposit: i128,
+    // Akışın başladığı Unix zaman damgası.
+    pub start: u64,
+    // Akışın bittiği Unix zaman damgası; bu andan sonra tüm miktar alıcınındır.
+    pub stop: u64,
+    // Alıcının şimdiye kadar çektiği (ücret dahil) miktar.
+    pub withdrawn: i128,
+}
+
+// `now` anına kadar alıcıya akmış toplam miktarı hesaplar.
+//
+// `deposit * geçen / süre` çarpımı `i128` sınırını aşabileceği için hesap
+// `deposit = hız * süre + kalan` şeklinde bölünerek yapılır: `hız * geçen <= deposit` olduğundan
+// ilk terim taşmaz, `kalan * geçen` ise iki `u64`'ten küçük sayının çarpımı olduğu için `u128`'e sığar.
+pub(crate) fn streamed_amount(stream: &Stream, now: u64) -> i128 {
+    if now <= stream.start {
+        return 0;
+    }
+    if now >= stream.stop {
+        return stream.deposit;
+    }
+    let duration = stream.stop - stream.start;
+    let elapsed = now - stream.start;
+    let rate = stream.deposit / duration as i128;
+    let remainder = (stream.deposit % duration as i128) as u128;
+    rate * elapsed as i128 + (remainder * elapsed as u128 / duration as u128) as i128
+}
+
+fn read_stream(env: &Env, stream_id: u64) -> Stream {
+    let key = DataKey::Stream(stream_id);
+    let stream = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, Error::BalanceNotFound));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    stream
+}
+
+fn write_stream(env: &Env, stream_id: u64, stream: &Stream) {
+    let key = DataKey::Stream(stream_id);
+    env.storage().persistent().set(&key, stream);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// Alıcıya `amount` kadar token öder; talep ücretini keser ve ödenen net miktarı döndürür.
+fn pay_recipient(env: &Env, stream_id: u64, stream: &Stream, amount: i128) -> i128 {
+    if amount == 0 {
+        return 0;
+    }
+    let (paid, fee) = fee::charge_claim_fee(env, &stream.token, amount);
+    token::Client::new(env, &stream.token).transfer(
+        &env.current_contract_address(),
+        &stream.recipient,
+        &paid,
+    );
+    events::claim(env, &stream.recipient, stream_id, &stream.token, paid, fee);
+    paid
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `sender`'dan `amount` kadar token yatırarak `start` ile `stop` arasında `recipient`'a
+    // akan bir ödeme akışı oluşturur ve kimliğini döndürür. Yatırma ücreti kesildikten sonra
+    // kalan net miktar akar; hız `net_miktar / (stop - start)` token/saniyedir.
+    pub fn create_stream(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token: Address,
+        amount: i128,
+        start: u64,
+        stop: u64,
+    ) -> u64 {
+        pause::ensure_not_paused(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if start >= stop || stop <= env.ledger().timestamp() {
+            panic_with_error!(&env, Error::InvalidSchedule);
+        }
+        sender.require_auth();
+        registry::ensure_allowed(&env, &sender);
+
+        let received = receive_tokens(&env, &token, |client| {
+            client.transfer(&sender, &env.current_contract_address(), &amount)
+        });
+        let stream_id = next_balance_id(&env);
+        let (deposit, fee) = fee::charge_deposit_fee(&env, &token, received);
+        events::deposit(&env, &sender, stream_id, &token, deposit, fee);
+        write_stream(
+            &env,
+            stream_id,
+            &Stream {
+                sender,
+                recipient,
+                token,
+                deposit,
+                start,
+                stop,
+                withdrawn: 0,
+            },
+        );
+        stream_id
+    }
+
+    // Alıcının şu ana kadar biriken ve henüz çekilmemiş miktarın tamamını çekmesini sağlar.
+    // Talep ücreti kesilir; alıcıya ödenen net miktarı döndürür.
+    // Akışın tamamı çekildiğinde kayıt silinir.
+    pub fn withdraw(env: Env, stream_id: u64) -> i128 {
+        pause::ensure_not_paused(&env);
+        let mut stream = read_stream(&env, stream_id);
+        stream.recipient.require_auth();
+        registry::ensure_allowed(&env, &stream.recipient);
+
+        let available = streamed_amount(&stream, env.ledger().timestamp()) - stream.withdrawn;
+        stream.withdrawn += available;
+        if stream.withdrawn == stream.deposit {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Stream(stream_id));
+        } else {
+            write_stream(&env, stream_id, &stream);
+        }
+        pay_recipient(&env, stream_id, &stream, available)
+    }
+
+    // Gönderenin akışı iptal etmesini sağlar. Birikmiş ve çekilmemiş miktar alıcıya,
+    // henüz akmamış miktar gönderene ödenir ve kayıt silinir.
+    // Kontrat durdurulmuşken, `reclaim` gibi sadece durdurma kuralları izin veriyorsa çalışır.
+    pub fn cancel_stream(env: Env, stream_id: u64) {
+        if !pause::read_policy(&env).allow_reclaim {
+            pause::ensure_not_paused(&env);
+        }
+        let stream = read_stream(&env, stream_id);
+        stream.sender.require_auth();
+
+        let streamed = streamed_amount(&stream, env.ledger().timestamp());
+        let unstreamed = stream.deposit - streamed;
+        // Token alacak taraflar, herhangi bir transferden önce kontrol edilir.
+        if unstreamed > 0 {
+            registry::ensure_allowed(&env, &stream.sender);
+        }
+        if streamed > stream.withdrawn {
+            registry::ensure_allowed(&env, &stream.recipient);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Stream(stream_id));
+
+        let paid = pay_recipient(&env, stream_id, &stream, streamed - stream.withdrawn);
+        if unstreamed > 0 {
+            token::Client::new(&env, &stream.token).transfer(
+                &env.current_contract_address(),
+                &stream.sender,
+                &unstreamed,
+            );
+        }
+        events::stream_cancelled(&env, &stream.sender, stream_id, &stream.token, paid, unstreamed);
+    }
+
+    // Alıcının şu anda çekebileceği miktarı (ücret kesilmeden önce) döndürür.
+    pub fn withdrawable(env: Env, stream_id: u64) -> i128 {
+        let stream = read_stream(&env, stream_id);
+        streamed_amount(&stream, env.ledger().timestamp()) - stream.withdrawn
+    }
+
+    // Verilen kimlikteki akışı döndürür; yoksa `None` döner.
+    pub fn get_stream(env: Env, stream_id: u64) -> Option<Stream> {
+        env.storage().persistent().get(&DataKey::Stream(stream_id))
+    }
+}
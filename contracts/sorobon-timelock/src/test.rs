@@ -16,7 +16,10 @@ use super::*;
 // - `AuthorizedFunction`: Yetkilendirilmesi beklenen bir fonksiyon çağrısını temsil eder.
 // - `AuthorizedInvocation`: Bir yetkilendirme çağrısının tüm detaylarını (fonksiyon, argümanlar, alt çağrılar) temsil eder.
 // - `Ledger`: Test ortamındaki defter (ledger) durumunu (örn: zaman damgası) değiştirmek için kullanılır.
-use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger};
+use soroban_sdk::testutils::{
+    Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger, MockAuth,
+    MockAuthInvoke,
+};
 // `soroban_sdk`'dan sık kullanılan diğer öğeleri içe aktarır:
 // - `symbol_short`: Kısa semboller oluşturmak için bir makro (genellikle fonksiyon isimleri için kullanılır).
 // - `token`: Token kontratlarıyla etkileşim için modül.
@@ -24,7 +27,9 @@ use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocat
 // - `Address`: Bir Soroban adresini temsil eder.
 // - `Env`: Kontratın çalıştığı Soroban ortamını temsil eder.
 // - `IntoVal`: Rust tiplerini Soroban'ın temel `Val` tipine dönüştürmek için bir trait.
-use soroban_sdk::{symbol_short, token, vec, Address, Env, IntoVal};
+use soroban_sdk::{
+    symbol_short, token, vec, Address, Bytes, BytesN, Env, IntoVal, Symbol, Val, Vec,
+};
 // `token` modülündeki `Client`'ı `TokenClient` olarak yeniden adlandırarak içe aktarır.
 // Bu, bir token kontratıyla etkileşim kurmak için kullanılır.
 use token::Client as TokenClient;
@@ -54,14 +59,16 @@ fn create_token_contract<'a>(e: &Env, admin: &Address) -> (TokenClient<'a>, Toke
 // Bu fonksiyon, test ettiğimiz `ClaimableBalanceContract`'ı test ortamında oluşturur (kaydeder/deploy eder)
 // ve bu kontratla etkileşim kurmak için bir istemci (ClaimableBalanceContractClient) döndürür.
 // `ClaimableBalanceContractClient`, Soroban SDK tarafından kontratınızın public arayüzüne göre otomatik olarak oluşturulur.
-fn create_claimable_balance_contract<'a>(e: &Env) -> ClaimableBalanceContractClient<'a> {
+fn create_claimable_balance_contract<'a>(
+    e: &Env,
+    admin: &Address,
+) -> ClaimableBalanceContractClient<'a> {
     // `ClaimableBalanceContractClient::new` ile yeni bir istemci oluşturulur.
-    // İkinci argüman olarak `e.register(ClaimableBalanceContract, ())` çağrılır:
+    // İkinci argüman olarak `e.register(ClaimableBalanceContract, (admin,))` çağrılır:
     //   - `ClaimableBalanceContract`: Deploy edilecek kontratımızın tipi.
-    //   - `()`: Kontratımızın `#[contract]` struct'ı için başlangıç argümanları. Bizim kontratımızda
-    //     `ClaimableBalanceContract` struct'ı boş olduğu için boş bir tuple (`()`) veriyoruz.
+    //   - `(admin,)`: Kontratın kurucu (`__constructor`) fonksiyonuna verilen argümanlar.
     // `e.register` fonksiyonu, kontratı deploy eder ve adresini döndürür.
-    ClaimableBalanceContractClient::new(e, &e.register(ClaimableBalanceContract, ()))
+    ClaimableBalanceContractClient::new(e, &e.register(ClaimableBalanceContract, (admin.clone(),)))
 }
 
 // Bu struct, her test için ortak olan test ortamını ve bileşenlerini bir arada tutar.
@@ -69,12 +76,16 @@ fn create_claimable_balance_contract<'a>(e: &Env) -> ClaimableBalanceContractCli
 struct ClaimableBalanceTest<'a> {
     // Soroban test ortamı.
     env: Env,
+    // Kontratın yöneticisi (yükseltme ve göç yetkisi).
+    admin: Address,
     // Token'ları yatıracak olan adres.
     deposit_address: Address,
     // Bakiyeyi talep edebilecek adreslerden oluşan bir dizi (bu örnekte 3 tane).
     claim_addresses: [Address; 3],
     // Testlerde kullanılacak token kontratının istemcisi.
     token: TokenClient<'a>,
+    // Aynı token kontratında basma (mint) gibi yönetici işlemleri için istemci.
+    token_admin: TokenAdminClient<'a>,
     // Test ettiğimiz `ClaimableBalanceContract`'ın istemcisi.
     contract: ClaimableBalanceContractClient<'a>,
 }
@@ -98,6 +109,9 @@ impl<'a> ClaimableBalanceTest<'a> {
             li.timestamp = 12345;
         });
 
+        // Kontrat yöneticisi için rastgele bir Soroban adresi oluşturur.
+        let admin = Address::generate(&env);
+
         // `deposit_address` için rastgele bir Soroban adresi oluşturur.
         let deposit_address = Address::generate(&env);
 
@@ -120,16 +134,99 @@ impl<'a> ClaimableBalanceTest<'a> {
 
         // `create_claimable_balance_contract` helper fonksiyonunu kullanarak
         // test ettiğimiz `ClaimableBalanceContract`'ı deploy eder ve istemcisini alır.
-        let contract = create_claimable_balance_contract(&env);
+        let contract = create_claimable_balance_contract(&env, &admin);
         // Ayarlanan tüm bileşenlerle `ClaimableBalanceTest` struct'ını oluşturup döndürür.
         ClaimableBalanceTest {
             env,
+            admin,
             deposit_address,
             claim_addresses,
             token,
+            token_admin: token_admin_client,
             contract,
         }
     }
+
+    // `claim_addresses` dizisinin verilen sıralarındaki adreslerden bir talepçi listesi oluşturur.
+    fn claimants(&self, indices: &[usize]) -> Vec<Address> {
+        let mut claimants = Vec::new(&self.env);
+        for &index in indices {
+            claimants.push_back(self.claim_addresses[index].clone());
+        }
+        claimants
+    }
+
+    // `deposit_address`'ten test token'ıyla `amount` tokenlık bir bakiye yatırır ve kimliğini döndürür.
+    fn deposit(&self, amount: i128, claimants: &Vec<Address>, time_bound: &TimeBound) -> u64 {
+        self.contract.deposit(
+            &self.deposit_address,
+            &self.token.address,
+            &amount,
+            claimants,
+            time_bound,
+        )
+    }
+
+    // Verilen adrese test token'ından `amount` birim basar.
+    fn mint(&self, to: &Address, amount: i128) {
+        self.token_admin.mint(to, &amount);
+    }
+
+    // Koruyucuyu atar, durdurma kurallarını ayarlar ve koruyucunun adresini döndürür.
+    fn set_guardian(&self, policy: &PausePolicy) -> Address {
+        let guardian = Address::generate(&self.env);
+        self.contract.set_guardian(&guardian);
+        self.contract.set_pause_policy(policy);
+        guardian
+    }
+
+    // Ücret tarifesini önerir, bekleme süresi dolduktan sonra yürürlüğe koyar ve hazineyi döndürür.
+    fn set_fees(&self, deposit_bps: u32, claim_bps: u32) -> Address {
+        let treasury = Address::generate(&self.env);
+        self.contract.propose_fee_config(&FeeConfig {
+            treasury: treasury.clone(),
+            deposit_bps,
+            claim_bps,
+        });
+        let now = self.env.ledger().timestamp();
+        self.env.ledger().set_timestamp(now + fee::FEE_UPDATE_DELAY);
+        self.contract.apply_fee_config();
+        treasury
+    }
+
+    // Verilen transfer ücretiyle bir fee-on-transfer token deploy eder ve yatırana 1000 birim basar.
+    fn fee_token(&self, fee_bps: i128) -> fee_token::FeeOnTransferTokenClient<'a> {
+        let address = self.env.register(fee_token::FeeOnTransferToken, (fee_bps,));
+        let token = fee_token::FeeOnTransferTokenClient::new(&self.env, &address);
+        token.mint(&self.deposit_address, &1000);
+        token
+    }
+
+    // Sahte bir kayıt kontratı deploy eder ve kontrata atar. Başlangıçta hiçbir adres izinli değildir.
+    fn set_registry(&self) -> registry::MockRegistryClient<'a> {
+        let registry = registry::MockRegistryClient::new(
+            &self.env,
+            &self.env.register(registry::MockRegistry, ()),
+        );
+        self.contract.set_registry(&Some(registry.address.clone()));
+        registry
+    }
+}
+
+// `timestamp`'e kadar (dahil) talep edilebilen bir zaman sınırı.
+fn before(timestamp: u64) -> TimeBound {
+    TimeBound {
+        kind: TimeBoundKind::Before,
+        timestamp,
+    }
+}
+
+// `timestamp`'ten itibaren talep edilebilen bir zaman sınırı.
+fn after(timestamp: u64) -> TimeBound {
+    TimeBound {
+        kind: TimeBoundKind::After,
+        timestamp,
+    }
 }
 
 // Bu, bir test fonksiyonudur. `#[test]` attribute'ü Rust'ın test çalıştırıcısının
@@ -144,7 +241,7 @@ fn test_deposit_and_claim() {
     // - `800`: Yatırılacak miktar.
     // - `vec![...]`: Talepçilerin listesi (bu durumda `claim_addresses[0]` ve `claim_addresses[1]`).
     // - `TimeBound`: Zaman sınırı (timestamp 12346'dan önce). Ledger timestamp'i 12345 olduğu için bu geçerli bir zaman.
-    test.contract.deposit(
+    let balance_id = test.contract.deposit(
         &test.deposit_address,
         &test.token.address,
         &800,
@@ -217,7 +314,7 @@ fn test_deposit_and_claim() {
     assert_eq!(test.token.balance(&test.claim_addresses[1]), 0);
 
     // `claim_addresses[1]` adresini kullanarak `claim` fonksiyonunu çağırır.
-    test.contract.claim(&test.claim_addresses[1]);
+    test.contract.claim(&balance_id, &test.claim_addresses[1]);
     // `claim` çağrısı için yetkilendirme kontrolü:
     // Beklenti: `claim_addresses[1]`, `claim` fonksiyonunu kendisi argümanıyla çağırmak için yetkilendirme yapmıştır.
     // Token transferi kontrattan olduğu için `claim_addresses[1]`'in ek bir alt çağrı yetkilendirmesi gerekmez.
@@ -229,7 +326,7 @@ fn test_deposit_and_claim() {
                 function: AuthorizedFunction::Contract((
                     test.contract.address.clone(),
                     symbol_short!("claim"),
-                    (test.claim_addresses[1].clone(),).into_val(&test.env), // Argüman: talep eden adres
+                    (balance_id, test.claim_addresses[1].clone()).into_val(&test.env), // Argümanlar: bakiye kimliği ve talep eden adres
                 )),
                 sub_invocations: std::vec![] // Beklenen alt çağrı yok
             }
@@ -245,35 +342,37 @@ fn test_deposit_and_claim() {
     assert_eq!(test.token.balance(&test.claim_addresses[1]), 800);
 }
 
-// Bu test, kontrata iki kez para yatırmanın mümkün olmadığını kontrol eder.
-// `#[should_panic(...)]` attribute'ü, testin paniklemesini beklediğimizi ve
-// panik mesajının `expected` string'ini içermesi gerektiğini belirtir.
+// Bu test, her yatırmanın ayrı bir kimlikle yeni bir bakiye oluşturduğunu ve
+// bakiyelerin birbirinden bağımsız talep edildiğini kontrol eder.
 #[test]
-#[should_panic(expected = "contract has been already initialized")]
-fn test_double_deposit_not_possible() {
+fn test_multiple_deposits_create_separate_balances() {
     let test = ClaimableBalanceTest::setup();
     // İlk deposit
-    test.contract.deposit(
-        &test.deposit_address,
-        &test.token.address,
-        &1,
-        &vec![&test.env, test.claim_addresses[0].clone()],
-        &TimeBound {
-            kind: TimeBoundKind::Before,
-            timestamp: 12346,
-        },
-    );
-    // İkinci deposit denemesi - bu paniklemeli.
-    test.contract.deposit(
-        &test.deposit_address,
-        &test.token.address,
-        &1,
-        &vec![&test.env, test.claim_addresses[0].clone()],
-        &TimeBound {
-            kind: TimeBoundKind::Before,
-            timestamp: 12346,
-        },
-    );
+    let first_id = test.deposit(100, &test.claimants(&[0]), &before(12346));
+    // İkinci deposit, farklı talepçilerle.
+    let second_id = test.deposit(300, &test.claimants(&[1]), &before(12346));
+    assert_eq!((first_id, second_id), (0, 1));
+    assert_eq!(test.token.balance(&test.contract.address), 400);
+
+    // Her talepçi sadece kendi bakiyesini alır.
+    test.contract.claim(&second_id, &test.claim_addresses[1]);
+    assert_eq!(test.token.balance(&test.claim_addresses[1]), 300);
+    assert_eq!(test.contract.get_balance(&second_id), None);
+    assert_eq!(test.contract.get_balance(&first_id).unwrap().amount, 100);
+    test.contract.claim(&first_id, &test.claim_addresses[0]);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 100);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+}
+
+// Bu test, bir talepçinin başka bir bakiyeyi kendi listesinde olmadığı için talep edemeyeceğini kontrol eder.
+#[test]
+#[should_panic(expected = "claimant is not allowed to claim this balance")]
+fn test_claim_of_other_balance_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    test.deposit(100, &test.claimants(&[0]), &before(12346));
+    let second_id = test.deposit(300, &test.claimants(&[1]), &before(12346));
+
+    test.contract.claim(&second_id, &test.claim_addresses[0]);
 }
 
 // Bu test, yetkisiz bir talepçinin bakiyeyi talep edemeyeceğini kontrol eder.
@@ -282,7 +381,7 @@ fn test_double_deposit_not_possible() {
 fn test_unauthorized_claim_not_possible() {
     let test = ClaimableBalanceTest::setup();
     // Deposit yapılır, talepçiler `claim_addresses[0]` ve `claim_addresses[1]`.
-    test.contract.deposit(
+    let balance_id = test.contract.deposit(
         &test.deposit_address,
         &test.token.address,
         &800,
@@ -298,7 +397,7 @@ fn test_unauthorized_claim_not_possible() {
     );
 
     // `claim_addresses[2]` (yetkisiz talepçi) talep etmeye çalışır - bu paniklemeli.
-    test.contract.claim(&test.claim_addresses[2]);
+    test.contract.claim(&balance_id, &test.claim_addresses[2]);
 }
 
 // Bu test, zaman sınırı karşılanmadığında bakiyenin talep edilemeyeceğini kontrol eder.
@@ -308,7 +407,7 @@ fn test_out_of_time_bound_claim_not_possible() {
     let test = ClaimableBalanceTest::setup();
     // Deposit yapılır. Zaman sınırı: timestamp `12346`'dan *sonra*.
     // Defterin mevcut zaman damgası `12345` (setup'ta ayarlandı).
-    test.contract.deposit(
+    let balance_id = test.contract.deposit(
         &test.deposit_address,
         &test.token.address,
         &800,
@@ -320,18 +419,18 @@ fn test_out_of_time_bound_claim_not_possible() {
     );
 
     // `claim_addresses[0]` talep etmeye çalışır. Zaman `12345`, koşul `12346`'dan sonra, bu yüzden paniklemeli.
-    test.contract.claim(&test.claim_addresses[0]);
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
 }
 
 // Bu test, aynı bakiyenin iki kez talep edilemeyeceğini kontrol eder.
 // `#[should_panic]` (expected olmadan) sadece herhangi bir panik bekler.
-// İlk `claim` başarılı olacak, ikinci `claim` ise `DataKey::Balance` depolamada bulunamadığı için
-// `env.storage().instance().get(&DataKey::Balance).unwrap()` satırında panikleyecektir.
+// İlk `claim` başarılı olacak, ikinci `claim` ise `DataKey::Balance(balance_id)` depolamada bulunamadığı için
+// `Error::BalanceNotFound` ile panikleyecektir.
 #[test]
 #[should_panic]
 fn test_double_claim_not_possible() {
     let test = ClaimableBalanceTest::setup();
-    test.contract.deposit(
+    let balance_id = test.contract.deposit(
         &test.deposit_address,
         &test.token.address,
         &800,
@@ -343,42 +442,3982 @@ fn test_double_claim_not_possible() {
     );
 
     // İlk talep (başarılı olmalı).
-    test.contract.claim(&test.claim_addresses[0]);
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
     assert_eq!(test.token.balance(&test.claim_addresses[0]), 800);
     // İkinci talep denemesi (aynı kişi tarafından) - bu paniklemeli.
-    test.contract.claim(&test.claim_addresses[0]);
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+}
+
+// Bu test, ikinci bir deposit'in ilk bakiyenin üzerine yazılmadığını ve ilk bakiyenin
+// kimliğiyle iki kez talep yapılamayacağını kontrol eder.
+#[test]
+fn test_double_deposit_does_not_reuse_balance_id() {
+    let test = ClaimableBalanceTest::setup();
+    let first_id = test.deposit(100, &test.claimants(&[0]), &after(12344));
+    let second_id = test.deposit(300, &test.claimants(&[0]), &after(12344));
+    assert_ne!(first_id, second_id);
+
+    test.contract.claim(&first_id, &test.claim_addresses[0]);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 100);
+    assert_eq!(
+        test.contract.try_claim(&first_id, &test.claim_addresses[0]),
+        Err(Ok(Error::BalanceNotFound.into()))
+    );
+    // İkinci bakiye etkilenmez.
+    assert_eq!(test.contract.get_balance(&second_id).unwrap().amount, 300);
+}
+
+// Bu test, talep edildikten sonra yapılan yeni bir deposit'in talep edilen kimliği
+// yeniden canlandırmadığını kontrol eder.
+#[test]
+fn test_deposit_after_claim_does_not_revive_claimed_id() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &after(12344));
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+
+    test.deposit(200, &test.claimants(&[0]), &after(12344));
+    assert_eq!(
+        test.contract.try_claim(&balance_id, &test.claim_addresses[0]),
+        Err(Ok(Error::BalanceNotFound.into()))
+    );
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 800);
+    assert_eq!(test.token.balance(&test.contract.address), 200);
 }
 
-// Bu test, bir bakiye talep edildikten sonra tekrar deposit yapılamayacağını kontrol eder.
-// Kontrat `Init` olarak işaretlendiği için ikinci deposit başarısız olmalıdır.
+// Bu test, bir bakiye talep edildikten sonra yeni bir deposit yapılabildiğini ve
+// yeni bakiyenin yeni bir kimlik aldığını kontrol eder.
 #[test]
-#[should_panic(expected = "contract has been already initialized")]
-fn test_deposit_after_claim_not_possible() {
+fn test_deposit_after_claim_creates_new_balance() {
     let test = ClaimableBalanceTest::setup();
     // Deposit yapılır. Zaman sınırı: `12344`'ten sonra. Defter zamanı `12345`, yani hemen talep edilebilir.
-    test.contract.deposit(
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &after(12344));
+
+    // Talep edilir.
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 800);
+    // Talep edildikten sonra yeni bir deposit yapılır; talep edilen bakiyenin kimliği tekrar kullanılmaz.
+    let new_id = test.deposit(200, &test.claimants(&[0]), &after(12344));
+    assert_eq!(new_id, balance_id + 1);
+    assert_eq!(test.token.balance(&test.contract.address), 200);
+}
+
+// Bu test, olmayan bir bakiyenin talebinin tipli hatayla reddedildiğini kontrol eder.
+#[test]
+fn test_claim_of_unknown_balance_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    assert_eq!(
+        test.contract.try_claim(&7, &test.claim_addresses[0]),
+        Err(Ok(Error::BalanceNotFound.into()))
+    );
+}
+
+// Şema sürümü 3'teki (yükseltilebilen ilk sürümlerden) eski kontratı taklit eder.
+// Yükseltme testlerinde "eski sürüm" olarak deploy edilir.
+mod v3 {
+    // Yükseltme testleri için şema 3 sürümünün kopyası: yöneticisi ve `upgrade` fonksiyonu
+    // vardır; tek bakiye yatıranıyla birlikte kalıcı (persistent) depolamada, `Init` işareti
+    // ve sürüm örnek (instance) depolamada tutulur.
+    use soroban_sdk::{contract, contractimpl, contracttype, token, Address, BytesN, Env, Vec};
+
+    #[derive(Clone)]
+    #[contracttype]
+    pub enum DataKey {
+        Init,
+        Balance,
+        Admin,
+        Version,
+    }
+
+    #[derive(Clone)]
+    #[contracttype]
+    pub enum TimeBoundKind {
+        Before,
+        After,
+    }
+
+    #[derive(Clone)]
+    #[contracttype]
+    pub struct TimeBound {
+        pub kind: TimeBoundKind,
+        pub timestamp: u64,
+    }
+
+    #[derive(Clone)]
+    #[contracttype]
+    pub struct ClaimableBalance {
+        pub token: Address,
+        pub amount: i128,
+        pub claimants: Vec<Address>,
+        pub time_bound: TimeBound,
+        pub depositor: Option<Address>,
+    }
+
+    #[contract]
+    pub struct ClaimableBalanceContractV3;
+
+    #[contractimpl]
+    impl ClaimableBalanceContractV3 {
+        pub fn __constructor(env: Env, admin: Address) {
+            env.storage().instance().set(&DataKey::Admin, &admin);
+            env.storage().instance().set(&DataKey::Version, &3_u32);
+        }
+
+        pub fn deposit(
+            env: Env,
+            from: Address,
+            token: Address,
+            amount: i128,
+            claimants: Vec<Address>,
+            time_bound: TimeBound,
+        ) {
+            if env.storage().instance().has(&DataKey::Init) {
+                panic!("contract has been already initialized");
+            }
+            from.require_auth();
+            token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+            env.storage().persistent().set(
+                &DataKey::Balance,
+                &ClaimableBalance {
+                    token,
+                    amount,
+                    time_bound,
+                    claimants,
+                    depositor: Some(from),
+                },
+            );
+            env.storage().instance().set(&DataKey::Init, &());
+        }
+
+        pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            admin.require_auth();
+            env.deployer().update_current_contract_wasm(new_wasm_hash);
+        }
+    }
+}
+
+// Şema sürümü 3'teki kontratı deploy eder ve yatıranın ilk talepçi lehine 12346'ya kadar açık
+// 800 tokenlık tek bakiyesini eski düzende yatırır.
+fn deploy_v3_with_balance(test: &ClaimableBalanceTest) -> Address {
+    let address = test
+        .env
+        .register(v3::ClaimableBalanceContractV3, (test.admin.clone(),));
+    v3::ClaimableBalanceContractV3Client::new(&test.env, &address).deposit(
         &test.deposit_address,
         &test.token.address,
         &800,
-        &vec![&test.env, test.claim_addresses[0].clone()],
-        &TimeBound {
-            kind: TimeBoundKind::After,
-            timestamp: 12344,
+        &test.claimants(&[0]),
+        &v3::TimeBound {
+            kind: v3::TimeBoundKind::Before,
+            timestamp: 12346,
         },
     );
+    address
+}
 
-    // Talep edilir.
-    test.contract.claim(&test.claim_addresses[0]);
+// Eski kontratın kendi `upgrade` fonksiyonuyla kodunu güncel sürümün Wasm'ıyla değiştirir.
+// Ağdaki yükseltmede olduğu gibi kurucu (constructor) çalışmaz ve depolama olduğu gibi kalır.
+fn upgrade_in_place(
+    test: &ClaimableBalanceTest,
+    address: &Address,
+) -> ClaimableBalanceContractClient<'static> {
+    let wasm_hash = test
+        .env
+        .deployer()
+        .upload_contract_wasm(wasm_fixtures::wasm("soroban-timelock"));
+    v3::ClaimableBalanceContractV3Client::new(&test.env, address).upgrade(&wasm_hash);
+    ClaimableBalanceContractClient::new(&test.env, address)
+}
+
+// Ağa yüklenebilecek en küçük geçerli kontrat Wasm'ını döndürür: yalnızca Soroban'ın
+// beklediği `contractenvmetav0` bölümünü (protokol 22 arayüz sürümü) içerir.
+fn minimal_contract_wasm(e: &Env) -> Bytes {
+    let mut wasm = Bytes::from_slice(e, b"\0asm\x01\0\0\0");
+    // Özel bölüm (id 0), uzunluk 30: 17 baytlık ad + 12 baytlık XDR girdisi.
+    wasm.extend_from_slice(b"\x00\x1e\x11contractenvmetav0");
+    wasm.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0]);
+    wasm
+}
+
+// Yeni deploy edilen bir kontratın güncel şema sürümüyle başladığını kontrol eder.
+#[test]
+fn test_new_contract_has_current_schema_version() {
+    let test = ClaimableBalanceTest::setup();
+    assert_eq!(test.contract.version(), upgrade::SCHEMA_VERSION);
+}
+
+// Bu test, `upgrade` fonksiyonunun yönetici yetkisi istediğini kontrol eder.
+#[test]
+fn test_upgrade_requires_admin() {
+    let test = ClaimableBalanceTest::setup();
+    let wasm_hash = test
+        .env
+        .deployer()
+        .upload_contract_wasm(minimal_contract_wasm(&test.env));
+
+    test.contract.upgrade(&wasm_hash);
+    assert_eq!(
+        test.env.auths(),
+        [(
+            test.admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "upgrade"),
+                    (wasm_hash.clone(),).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![]
+            }
+        ),]
+    );
+}
+
+// Bu test, yönetici olmayan bir adresin kontratı yükseltemeyeceğini kontrol eder.
+#[test]
+#[should_panic]
+fn test_upgrade_by_non_admin_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let wasm_hash = test
+        .env
+        .deployer()
+        .upload_contract_wasm(minimal_contract_wasm(&test.env));
+    let attacker = Address::generate(&test.env);
+
+    // Yetkiler sadece saldırgan için taklit edilir; yönetici imzası yoktur.
+    test.env.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &test.contract.address,
+            fn_name: "upgrade",
+            args: (wasm_hash.clone(),).into_val(&test.env),
+            sub_invokes: &[],
+        },
+    }]);
+    test.contract.upgrade(&wasm_hash);
+}
+
+// Bu test, eski sürümde yatırılan bir bakiyenin yükseltme ve göç sonrasında
+// talep edilebilir kaldığını kontrol eder.
+#[test]
+fn test_balance_claimable_after_upgrade_and_migrate() {
+    let test = ClaimableBalanceTest::setup();
+    // Eski sürüm (şema 3) deploy edilir ve bakiye eski düzende yatırılır.
+    let address = deploy_v3_with_balance(&test);
+    let contract = upgrade_in_place(&test, &address);
+    assert_eq!(contract.version(), 3);
+
+    contract.migrate();
+    assert_eq!(contract.version(), upgrade::SCHEMA_VERSION);
+    // Göç tekrar çağrıldığında bir şey yapmaz.
+    contract.migrate();
+
+    // Eski tek bakiye 0 kimliğini alır; yeni yatırmalar 1'den devam eder.
+    contract.claim(&0, &test.claim_addresses[0]);
     assert_eq!(test.token.balance(&test.claim_addresses[0]), 800);
-    // Talep edildikten sonra tekrar deposit yapmaya çalışılır - bu paniklemeli.
-    test.contract.deposit(
+    assert_eq!(test.token.balance(&address), 0);
+    let new_id = contract.deposit(
         &test.deposit_address,
         &test.token.address,
-        &200,
-        &vec![&test.env, test.claim_addresses[0].clone()],
-        &TimeBound {
-            kind: TimeBoundKind::After,
-            timestamp: 12344,
-        },
+        &100,
+        &test.claimants(&[0]),
+        &before(12346),
+    );
+    assert_eq!(new_id, 1);
+}
+
+// Bu test, göç yapılmadan eski düzendeki bakiyenin yeni kod tarafından görülmediğini kontrol eder.
+#[test]
+#[should_panic]
+fn test_claim_before_migrate_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let address = deploy_v3_with_balance(&test);
+    let contract = upgrade_in_place(&test, &address);
+
+    contract.claim(&0, &test.claim_addresses[0]);
+}
+
+// Bu test, kontrat durdurulmuşken deposit yapılamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "contract is paused")]
+fn test_deposit_while_paused_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_guardian(&PausePolicy {
+        max_duration: pause::DEFAULT_MAX_PAUSE_DURATION,
+        allow_reclaim: false,
+    });
+    test.contract.pause(&None);
+    assert!(test.contract.is_paused());
+
+    test.deposit(800, &test.claimants(&[0]), &before(12346));
+}
+
+// Bu test, kontrat durdurulmuşken talep yapılamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "contract is paused")]
+fn test_claim_while_paused_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_guardian(&PausePolicy {
+        max_duration: pause::DEFAULT_MAX_PAUSE_DURATION,
+        allow_reclaim: false,
+    });
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &before(12346));
+    test.contract.pause(&None);
+
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+}
+
+// Bu test, durdurmanın sadece koruyucu tarafından yapılabileceğini kontrol eder.
+#[test]
+fn test_pause_requires_guardian() {
+    let test = ClaimableBalanceTest::setup();
+    let guardian = test.set_guardian(&PausePolicy {
+        max_duration: pause::DEFAULT_MAX_PAUSE_DURATION,
+        allow_reclaim: false,
+    });
+
+    test.contract.pause(&Some(20000));
+    assert_eq!(
+        test.env.auths(),
+        [(
+            guardian.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    symbol_short!("pause"),
+                    (Some(20000_u64),).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![]
+            }
+        ),]
+    );
+
+    test.contract.unpause();
+    assert!(!test.contract.is_paused());
+}
+
+// Bu test, süreli bir durdurmanın süresi dolunca kendiliğinden kalktığını kontrol eder.
+#[test]
+fn test_pause_expires_automatically() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_guardian(&PausePolicy {
+        max_duration: 100,
+        allow_reclaim: false,
+    });
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &after(12345));
+
+    // Bitiş zamanı verilmez; kurallardaki en uzun süre (100 saniye) uygulanır.
+    test.contract.pause(&None);
+    test.env.ledger().with_mut(|li| li.timestamp = 12444);
+    assert!(test.contract.is_paused());
+    test.env.ledger().with_mut(|li| li.timestamp = 12445);
+    assert!(!test.contract.is_paused());
+
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 800);
+}
+
+// Bu test, koruyucunun en uzun süreyi aşan bir durdurma yapamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "pause exceeds maximum duration")]
+fn test_pause_longer_than_max_duration_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_guardian(&PausePolicy {
+        max_duration: 100,
+        allow_reclaim: false,
+    });
+    test.contract.pause(&Some(12446));
+}
+
+// Bu test, süren bir durdurmanın yeni bir durdurmayla uzatılamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "contract is already paused")]
+fn test_pause_while_paused_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_guardian(&PausePolicy {
+        max_duration: 100,
+        allow_reclaim: false,
+    });
+    test.contract.pause(&None);
+    test.env.ledger().with_mut(|li| li.timestamp = 12444);
+
+    test.contract.pause(&None);
+}
+
+// Bu test, arka arkaya durdurmalarla kontratın en uzun süreden fazla dondurulamayacağını
+// kontrol eder: durdurma bittikten sonra en uzun süre kadar yeniden durdurulamaz.
+#[test]
+fn test_repause_cannot_exceed_max_duration() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_guardian(&PausePolicy {
+        max_duration: 100,
+        allow_reclaim: false,
+    });
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &after(12345));
+
+    // İlk durdurma 12445'te kendiliğinden kalkar; hemen ardından yeniden durdurulamaz.
+    test.contract.pause(&None);
+    test.env.ledger().with_mut(|li| li.timestamp = 12445);
+    assert!(test.contract.try_pause(&None).is_err());
+    assert!(!test.contract.is_paused());
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+
+    // Bekleme süresi dolduktan sonra yeniden durdurulabilir.
+    test.env.ledger().with_mut(|li| li.timestamp = 12545);
+    test.contract.pause(&None);
+    assert!(test.contract.is_paused());
+}
+
+// Bu test, erken kaldırılan bir durdurmanın ardından da bekleme süresinin uygulandığını
+// kontrol eder.
+#[test]
+#[should_panic(expected = "pause cooldown has not elapsed")]
+fn test_repause_after_unpause_not_possible_before_cooldown() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_guardian(&PausePolicy {
+        max_duration: 100,
+        allow_reclaim: false,
+    });
+    test.contract.pause(&None);
+    test.env.ledger().with_mut(|li| li.timestamp = 12400);
+    test.contract.unpause();
+    assert!(!test.contract.is_paused());
+
+    test.env.ledger().with_mut(|li| li.timestamp = 12499);
+    test.contract.pause(&None);
+}
+
+// Bu test, kurallar ayarlanmamışsa da durdurmanın varsayılan en uzun süre sonunda kalktığını
+// kontrol eder.
+#[test]
+fn test_pause_bounded_by_default() {
+    let test = ClaimableBalanceTest::setup();
+    test.contract.set_guardian(&Address::generate(&test.env));
+    assert!(test
+        .contract
+        .try_pause(&Some(12345 + pause::DEFAULT_MAX_PAUSE_DURATION + 1))
+        .is_err());
+
+    test.contract.pause(&None);
+    let end = 12345 + pause::DEFAULT_MAX_PAUSE_DURATION;
+    test.env.ledger().with_mut(|li| li.timestamp = end - 1);
+    assert!(test.contract.is_paused());
+    test.env.ledger().with_mut(|li| li.timestamp = end);
+    assert!(!test.contract.is_paused());
+}
+
+// Bu test, yöneticinin sıfır veya üst sınırı aşan bir en uzun durdurma süresi belirleyemediğini
+// kontrol eder.
+#[test]
+fn test_set_pause_policy_requires_bounded_duration() {
+    let test = ClaimableBalanceTest::setup();
+    for max_duration in [0, pause::MAX_PAUSE_DURATION + 1] {
+        assert!(test
+            .contract
+            .try_set_pause_policy(&PausePolicy {
+                max_duration,
+                allow_reclaim: false,
+            })
+            .is_err());
+    }
+    test.contract.set_pause_policy(&PausePolicy {
+        max_duration: pause::MAX_PAUSE_DURATION,
+        allow_reclaim: false,
+    });
+}
+
+// Bu test, süresi dolan bir bakiyenin yatıran tarafından geri alınabildiğini kontrol eder.
+#[test]
+fn test_reclaim_after_expiry() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &before(12346));
+    test.env.ledger().with_mut(|li| li.timestamp = 12347);
+
+    test.contract.reclaim(&balance_id);
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+}
+
+// Bu test, hâlâ talep edilebilen bir bakiyenin geri alınamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "balance can still be claimed")]
+fn test_reclaim_before_expiry_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &before(12346));
+
+    test.contract.reclaim(&balance_id);
+}
+
+// Bu test, kurallar izin veriyorsa durdurma sırasında geri almanın açık kaldığını kontrol eder.
+#[test]
+fn test_reclaim_while_paused_if_allowed() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_guardian(&PausePolicy {
+        max_duration: pause::DEFAULT_MAX_PAUSE_DURATION,
+        allow_reclaim: true,
+    });
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &before(12346));
+    test.contract.pause(&None);
+    test.env.ledger().with_mut(|li| li.timestamp = 12347);
+
+    test.contract.reclaim(&balance_id);
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+}
+
+// Bu test, kurallar izin vermiyorsa durdurma sırasında geri alınamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "contract is paused")]
+fn test_reclaim_while_paused_not_possible_by_default() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_guardian(&PausePolicy {
+        max_duration: pause::DEFAULT_MAX_PAUSE_DURATION,
+        allow_reclaim: false,
+    });
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &before(12346));
+    test.contract.pause(&None);
+    test.env.ledger().with_mut(|li| li.timestamp = 12347);
+
+    test.contract.reclaim(&balance_id);
+}
+
+// Test ortamında yayınlanan son olayı tek elemanlı bir liste olarak döndürür.
+fn last_event(e: &Env) -> Vec<(Address, Vec<Val>, Val)> {
+    let events = e.events().all();
+    events.slice(events.len() - 1..)
+}
+
+// Bu test, ücret hesabının aşağı yuvarlandığını ve büyük miktarlarda da kesin olduğunu kontrol eder.
+#[test]
+fn test_fee_math_rounds_down() {
+    assert_eq!(fee::compute_fee(10_000, 25), 25);
+    assert_eq!(fee::compute_fee(9_999, 25), 24);
+    assert_eq!(fee::compute_fee(39, 250), 0);
+    assert_eq!(fee::compute_fee(40, 250), 1);
+    assert_eq!(fee::compute_fee(1, fee::MAX_FEE_BPS), 0);
+    assert_eq!(fee::compute_fee(801, 0), 0);
+    assert_eq!(
+        fee::compute_fee(i128::MAX / 1_000, fee::MAX_FEE_BPS),
+        (i128::MAX / 1_000) / 10
+    );
+}
+
+// Bu test, ücret hesabında taşma olursa işlemin panikleyeceğini kontrol eder.
+#[test]
+#[should_panic(expected = "fee calculation overflow")]
+fn test_fee_math_overflow_not_possible() {
+    fee::compute_fee(i128::MAX, 2);
+}
+
+// Bu test, yatırma ve talep ücretlerinin kesin olarak hazineye gönderildiğini
+// ve olaylara net miktarların yazıldığını kontrol eder.
+#[test]
+fn test_deposit_and_claim_with_fees() {
+    let test = ClaimableBalanceTest::setup();
+    let treasury = test.set_fees(250, 100);
+    let now = test.env.ledger().timestamp();
+
+    let balance_id = test.deposit(801, &test.claimants(&[0]), &after(now));
+    // Olaylar sadece son çağrı için tutulduğundan, bakiyelerden önce kontrol edilir.
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("deposit"), test.deposit_address.clone()).into_val(&test.env),
+                (balance_id, test.token.address.clone(), 781_i128, 20_i128).into_val(&test.env),
+            ),
+        ]
     );
-}
\ No newline at end of file
+    // 801 * 250 / 10.000 = 20,025 -> 20 ücret, 781 net.
+    assert_eq!(test.token.balance(&treasury), 20);
+    assert_eq!(test.token.balance(&test.contract.address), 781);
+
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("claim"), test.claim_addresses[0].clone()).into_val(&test.env),
+                (balance_id, test.token.address.clone(), 774_i128, 7_i128).into_val(&test.env),
+            ),
+        ]
+    );
+    // 781 * 100 / 10.000 = 7,81 -> 7 ücret, 774 net.
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 774);
+    assert_eq!(test.token.balance(&treasury), 27);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+}
+
+// Bu test, önerilen ücret tarifesinin bekleme süresi dolmadan uygulanamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "fee config update is still timelocked")]
+fn test_fee_config_update_is_timelocked() {
+    let test = ClaimableBalanceTest::setup();
+    test.contract.propose_fee_config(&FeeConfig {
+        treasury: Address::generate(&test.env),
+        deposit_bps: 10,
+        claim_bps: 10,
+    });
+    assert_eq!(test.contract.fee_config(), None);
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = 12345 + fee::FEE_UPDATE_DELAY - 1);
+    test.contract.apply_fee_config();
+}
+
+// Bu test, üst sınırı aşan bir ücretin önerilemeyeceğini kontrol eder.
+#[test]
+#[should_panic(expected = "fee exceeds maximum")]
+fn test_fee_above_maximum_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    test.contract.propose_fee_config(&FeeConfig {
+        treasury: Address::generate(&test.env),
+        deposit_bps: 0,
+        claim_bps: fee::MAX_FEE_BPS + 1,
+    });
+}
+
+// Yatırma girdilerinin doğrulanmasına ait testler. Her geçersiz girdi, ilgili tipli
+// hatayla (`Error`) reddedilmeli ve hiçbir token hareket etmemelidir.
+mod validation {
+    use super::*;
+
+    // Verilen talepçilerle `try_deposit` çağırır ve sonucu döndürür.
+    fn try_deposit(
+        test: &ClaimableBalanceTest,
+        amount: i128,
+        claimants: &Vec<Address>,
+    ) -> Result<(), Error> {
+        let result = test.contract.try_deposit(
+            &test.deposit_address,
+            &test.token.address,
+            &amount,
+            claimants,
+            &before(12346),
+        );
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                // Reddedilen bir yatırma hiçbir token hareket ettirmemeli.
+                assert_eq!(test.token.balance(&test.deposit_address), 1000);
+                Err(Error::try_from(err.unwrap()).unwrap())
+            }
+        }
+    }
+
+    // `n` adet farklı talepçi adresi üretir.
+    fn claimants(e: &Env, n: u32) -> Vec<Address> {
+        let mut claimants = Vec::new(e);
+        for _ in 0..n {
+            claimants.push_back(Address::generate(e));
+        }
+        claimants
+    }
+
+    #[test]
+    fn test_zero_amount_rejected() {
+        let test = ClaimableBalanceTest::setup();
+        let claimants = claimants(&test.env, 1);
+        assert_eq!(try_deposit(&test, 0, &claimants), Err(Error::InvalidAmount));
+    }
+
+    #[test]
+    fn test_negative_amount_rejected() {
+        let test = ClaimableBalanceTest::setup();
+        let claimants = claimants(&test.env, 1);
+        assert_eq!(try_deposit(&test, -1, &claimants), Err(Error::InvalidAmount));
+        assert_eq!(
+            try_deposit(&test, i128::MIN, &claimants),
+            Err(Error::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn test_empty_claimants_rejected() {
+        let test = ClaimableBalanceTest::setup();
+        let claimants = Vec::new(&test.env);
+        assert_eq!(try_deposit(&test, 800, &claimants), Err(Error::NoClaimants));
+    }
+
+    #[test]
+    fn test_duplicate_claimants_rejected() {
+        let test = ClaimableBalanceTest::setup();
+        let claimants = test.claimants(&[0, 1, 0]);
+        assert_eq!(
+            try_deposit(&test, 800, &claimants),
+            Err(Error::DuplicateClaimant)
+        );
+    }
+
+    #[test]
+    fn test_too_many_claimants_rejected() {
+        let test = ClaimableBalanceTest::setup();
+        let claimants = claimants(&test.env, crate::validation::DEFAULT_MAX_CLAIMANTS + 1);
+        assert_eq!(
+            try_deposit(&test, 800, &claimants),
+            Err(Error::TooManyClaimants)
+        );
+    }
+
+    #[test]
+    fn test_max_claimants_accepted() {
+        let test = ClaimableBalanceTest::setup();
+        let claimants = claimants(&test.env, crate::validation::DEFAULT_MAX_CLAIMANTS);
+        test.deposit(800, &claimants, &before(12346));
+        assert_eq!(test.token.balance(&test.contract.address), 800);
+    }
+
+    #[test]
+    fn test_configured_max_claimants() {
+        let test = ClaimableBalanceTest::setup();
+        test.contract.set_max_claimants(&2);
+        assert_eq!(test.contract.max_claimants(), 2);
+
+        let claimants = claimants(&test.env, 3);
+        assert_eq!(
+            try_deposit(&test, 800, &claimants),
+            Err(Error::TooManyClaimants)
+        );
+        assert_eq!(try_deposit(&test, 800, &claimants.slice(0..2)), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_max_claimants_rejected() {
+        let test = ClaimableBalanceTest::setup();
+        assert_eq!(
+            test.contract.try_set_max_claimants(&0),
+            Err(Ok(Error::InvalidMaxClaimants.into()))
+        );
+        assert_eq!(
+            test.contract
+                .try_set_max_claimants(&(crate::validation::MAX_CLAIMANTS_LIMIT + 1)),
+            Err(Ok(Error::InvalidMaxClaimants.into()))
+        );
+    }
+}
+
+// Her transferde gönderilen miktarın `fee_bps` baz puanını kesen (fee-on-transfer) basit bir token.
+// Sadece kontratın kullandığı `balance` ve `transfer` fonksiyonlarını ve test için `mint`'i sağlar.
+mod fee_token {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    #[contract]
+    pub struct FeeOnTransferToken;
+
+    fn read_balance(env: &Env, id: &Address) -> i128 {
+        env.storage().persistent().get(id).unwrap_or(0)
+    }
+
+    #[contractimpl]
+    impl FeeOnTransferToken {
+        pub fn __constructor(env: Env, fee_bps: i128) {
+            env.storage().instance().set(&symbol_short!("fee_bps"), &fee_bps);
+        }
+
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            env.storage()
+                .persistent()
+                .set(&to, &(read_balance(&env, &to) + amount));
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            read_balance(&env, &id)
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let fee_bps: i128 = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("fee_bps"))
+                .unwrap();
+            let fee = amount * fee_bps / 10_000;
+            env.storage()
+                .persistent()
+                .set(&from, &(read_balance(&env, &from) - amount));
+            env.storage()
+                .persistent()
+                .set(&to, &(read_balance(&env, &to) + amount - fee));
+        }
+    }
+}
+
+// Bu test, transferde ücret kesen bir token yatırıldığında bakiyeye kontrata gerçekten
+// ulaşan miktarın kaydedildiğini ve bu miktarın eksiksiz talep edilebildiğini kontrol eder.
+#[test]
+fn test_fee_on_transfer_token_records_received_amount() {
+    let test = ClaimableBalanceTest::setup();
+    // Her transferde %1 kesilir.
+    let token = test.fee_token(100);
+
+    let balance_id = test.contract.deposit(
+        &test.deposit_address,
+        &token.address,
+        &800,
+        &test.claimants(&[0]),
+        &before(12346),
+    );
+    // Olay, istenen 800 değil kontrata ulaşan 792 miktarını içerir.
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("deposit"), test.deposit_address.clone()).into_val(&test.env),
+                (balance_id, token.address.clone(), 792_i128, 0_i128).into_val(&test.env),
+            ),
+        ]
+    );
+    assert_eq!(token.balance(&test.deposit_address), 200);
+    assert_eq!(token.balance(&test.contract.address), 792);
+
+    // Kayıtlı miktar kontratın elindekini aşmadığı için talep başarılı olur.
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+    assert_eq!(token.balance(&test.contract.address), 0);
+    assert_eq!(token.balance(&test.claim_addresses[0]), 785);
+}
+
+// Bu test, kontrata hiç token ulaşmayan bir yatırmanın reddedildiğini kontrol eder.
+#[test]
+fn test_deposit_with_nothing_received_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    // Transfer edilen miktarın tamamı kesilir.
+    let token = test.fee_token(10_000);
+
+    let result = test.contract.try_deposit(
+        &test.deposit_address,
+        &token.address,
+        &800,
+        &test.claimants(&[0]),
+        &before(12346),
+    );
+    assert_eq!(result, Err(Ok(Error::NothingReceived.into())));
+}
+
+// Bu test, bir operatörün yatıranın harcama izninden yatırma yapabildiğini ve
+// yatıranın bu çağrıyı imzalaması gerekmediğini kontrol eder.
+#[test]
+fn test_deposit_from_allowance() {
+    let test = ClaimableBalanceTest::setup();
+    let operator = Address::generate(&test.env);
+    test.token
+        .approve(&test.deposit_address, &test.contract.address, &900, &1000);
+    test.contract
+        .set_operator(&test.deposit_address, &operator, &true);
+    assert!(test.contract.is_operator(&test.deposit_address, &operator));
+
+    let balance_id = test.contract.deposit_from_allowance(
+        &operator,
+        &test.deposit_address,
+        &test.token.address,
+        &800,
+        &test.claimants(&[0]),
+        &before(12346),
+    );
+    // Sadece operatör yetkilendirmiştir; token çekimi önceden verilen izinle yapılır.
+    assert_eq!(
+        test.env.auths(),
+        [(
+            operator.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "deposit_from_allowance"),
+                    (
+                        operator.clone(),
+                        test.deposit_address.clone(),
+                        test.token.address.clone(),
+                        800_i128,
+                        test.claimants(&[0]),
+                        before(12346),
+                    )
+                        .into_val(&test.env),
+                )),
+                sub_invocations: std::vec![]
+            }
+        ),]
+    );
+    assert_eq!(test.token.balance(&test.deposit_address), 200);
+    assert_eq!(
+        test.token
+            .allowance(&test.deposit_address, &test.contract.address),
+        100
+    );
+
+    // Yatıran olarak `deposit_address` kaydedilmiştir ve süre dolunca geri alabilir.
+    test.env.ledger().with_mut(|li| li.timestamp = 12347);
+    test.contract.reclaim(&balance_id);
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+}
+
+// Bu test, yatıranın yetkilendirmediği bir operatörün harcama izninden yatırma yapamayacağını kontrol eder.
+#[test]
+fn test_deposit_from_allowance_by_unapproved_operator_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let operator = Address::generate(&test.env);
+    test.token
+        .approve(&test.deposit_address, &test.contract.address, &900, &1000);
+
+    let result = test.contract.try_deposit_from_allowance(
+        &operator,
+        &test.deposit_address,
+        &test.token.address,
+        &800,
+        &vec![&test.env, operator.clone()],
+        &before(12346),
+    );
+    assert_eq!(result, Err(Ok(Error::OperatorNotApproved.into())));
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+}
+
+// Bu test, harcama izni yetersizse yatırmanın başarısız olduğunu kontrol eder.
+#[test]
+#[should_panic]
+fn test_deposit_from_allowance_above_allowance_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let operator = Address::generate(&test.env);
+    test.token
+        .approve(&test.deposit_address, &test.contract.address, &500, &1000);
+    test.contract
+        .set_operator(&test.deposit_address, &operator, &true);
+
+    test.contract.deposit_from_allowance(
+        &operator,
+        &test.deposit_address,
+        &test.token.address,
+        &800,
+        &test.claimants(&[0]),
+        &before(12346),
+    );
+}
+
+// Bu test, yatıranın mevcut bir bakiyeye token ekleyebildiğini ve eklenen miktarın talep edildiğini kontrol eder.
+#[test]
+fn test_top_up_by_depositor() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(600, &test.claimants(&[0]), &before(12346));
+
+    test.contract
+        .top_up(&balance_id, &test.deposit_address, &300);
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("top_up"), test.deposit_address.clone()).into_val(&test.env),
+                (balance_id, test.token.address.clone(), 300_i128, 0_i128).into_val(&test.env),
+            ),
+        ]
+    );
+    assert_eq!(test.contract.get_balance(&balance_id).unwrap().amount, 900);
+    assert_eq!(test.token.balance(&test.deposit_address), 100);
+
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 900);
+}
+
+// Bu test, varsayılan kuralda yatıran dışında birinin bakiyeye token ekleyemeyeceğini kontrol eder.
+#[test]
+fn test_top_up_by_other_not_possible_by_default() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(600, &test.claimants(&[0]), &before(12346));
+    let sponsor = Address::generate(&test.env);
+    test.mint(&sponsor, 500);
+
+    assert_eq!(
+        test.contract.try_top_up(&balance_id, &sponsor, &300),
+        Err(Ok(Error::TopUpNotAllowed.into()))
+    );
+    assert_eq!(test.token.balance(&sponsor), 500);
+}
+
+// Bu test, yatıran izin verdikten sonra herkesin bakiyeye token ekleyebildiğini kontrol eder.
+#[test]
+fn test_top_up_by_anyone_when_allowed() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(600, &test.claimants(&[0]), &before(12346));
+    let sponsor = Address::generate(&test.env);
+    test.mint(&sponsor, 500);
+
+    test.contract
+        .set_top_up_policy(&balance_id, &TopUpPolicy::Anyone);
+    assert_eq!(
+        test.env.auths()[0].0,
+        test.deposit_address,
+        "only the depositor can change the top-up policy"
+    );
+    test.contract.top_up(&balance_id, &sponsor, &300);
+
+    assert_eq!(test.token.balance(&sponsor), 200);
+    assert_eq!(test.contract.get_balance(&balance_id).unwrap().amount, 900);
+}
+
+// Bu test, talep edilmiş bir bakiyeye token eklenemeyeceğini kontrol eder.
+#[test]
+fn test_top_up_after_claim_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(600, &test.claimants(&[0]), &before(12346));
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+
+    assert_eq!(
+        test.contract
+            .try_top_up(&balance_id, &test.deposit_address, &300),
+        Err(Ok(Error::BalanceNotFound.into()))
+    );
+}
+
+// Bu test, eklenen miktar için de yatırma ücretinin kesildiğini kontrol eder.
+#[test]
+fn test_top_up_charges_deposit_fee() {
+    let test = ClaimableBalanceTest::setup();
+    let treasury = test.set_fees(100, 0);
+    let balance_id = test.deposit(600, &test.claimants(&[0]), &after(0));
+
+    test.contract
+        .top_up(&balance_id, &test.deposit_address, &300);
+    // 600'den 6, 300'den 3 ücret kesilir.
+    assert_eq!(test.token.balance(&treasury), 9);
+    assert_eq!(test.contract.get_balance(&balance_id).unwrap().amount, 891);
+}
+
+// Test tarafında Merkle ağacı kurar. Her seviyedeki tek kalan düğüm bir üst seviyeye aynen taşınır.
+struct MerkleTree {
+    levels: std::vec::Vec<std::vec::Vec<BytesN<32>>>,
+}
+
+impl MerkleTree {
+    fn new(e: &Env, leaves: &[(Address, i128)]) -> Self {
+        let mut level: std::vec::Vec<BytesN<32>> = leaves
+            .iter()
+            .enumerate()
+            .map(|(index, (claimant, amount))| {
+                merkle::leaf_hash(e, index as u32, claimant, *amount)
+            })
+            .collect();
+        let mut levels = std::vec![level.clone()];
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => merkle::node_hash(e, a, b),
+                    [a] => a.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(level.clone());
+        }
+        MerkleTree { levels }
+    }
+
+    fn root(&self) -> BytesN<32> {
+        self.levels.last().unwrap()[0].clone()
+    }
+
+    fn proof(&self, e: &Env, mut index: usize) -> Vec<BytesN<32>> {
+        let mut proof = Vec::new(e);
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push_back(sibling.clone());
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
+
+// Üç talepçiye 100, 200 ve 300 token dağıtan ve 12346'ya kadar talep edilebilen bir
+// Merkle dağıtımı oluşturur.
+fn setup_merkle(test: &ClaimableBalanceTest) -> (u64, MerkleTree) {
+    let leaves = [
+        (test.claim_addresses[0].clone(), 100),
+        (test.claim_addresses[1].clone(), 200),
+        (test.claim_addresses[2].clone(), 300),
+    ];
+    let tree = MerkleTree::new(&test.env, &leaves);
+    let distribution_id = test.contract.deposit_merkle(
+        &test.deposit_address,
+        &test.token.address,
+        &600,
+        &tree.root(),
+        &(leaves.len() as u32),
+        &before(12346),
+    );
+    (distribution_id, tree)
+}
+
+// Bu test, talepçilerin Merkle ispatıyla kendi paylarını alabildiğini kontrol eder.
+#[test]
+fn test_merkle_claim() {
+    let test = ClaimableBalanceTest::setup();
+    let (distribution_id, tree) = setup_merkle(&test);
+    assert_eq!(test.token.balance(&test.contract.address), 600);
+
+    test.contract.claim_merkle(
+        &distribution_id,
+        &1,
+        &test.claim_addresses[1],
+        &200,
+        &tree.proof(&test.env, 1),
+    );
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("claim"), test.claim_addresses[1].clone()).into_val(&test.env),
+                (distribution_id, test.token.address.clone(), 200_i128, 0_i128)
+                    .into_val(&test.env),
+            ),
+        ]
+    );
+    test.contract.claim_merkle(
+        &distribution_id,
+        &2,
+        &test.claim_addresses[2],
+        &300,
+        &tree.proof(&test.env, 2),
+    );
+
+    assert_eq!(test.token.balance(&test.claim_addresses[1]), 200);
+    assert_eq!(test.token.balance(&test.claim_addresses[2]), 300);
+    assert!(!test.contract.is_leaf_claimed(&distribution_id, &0));
+    assert!(test.contract.is_leaf_claimed(&distribution_id, &1));
+    assert_eq!(
+        test.contract
+            .get_distribution(&distribution_id)
+            .unwrap()
+            .remaining,
+        100
+    );
+}
+
+// Bu test, aynı yaprağın iki kez talep edilemeyeceğini kontrol eder.
+#[test]
+fn test_merkle_double_claim_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let (distribution_id, tree) = setup_merkle(&test);
+    let proof = tree.proof(&test.env, 0);
+    test.contract
+        .claim_merkle(&distribution_id, &0, &test.claim_addresses[0], &100, &proof);
+
+    assert_eq!(
+        test.contract.try_claim_merkle(
+            &distribution_id,
+            &0,
+            &test.claim_addresses[0],
+            &100,
+            &proof
+        ),
+        Err(Ok(Error::AlreadyClaimed.into()))
+    );
+}
+
+// Bu test, yapraktakinden farklı bir miktarın veya başkasının ispatının kabul edilmediğini kontrol eder.
+#[test]
+fn test_merkle_claim_with_invalid_proof_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let (distribution_id, tree) = setup_merkle(&test);
+    let proof = tree.proof(&test.env, 0);
+
+    // Yanlış miktar.
+    assert_eq!(
+        test.contract.try_claim_merkle(
+            &distribution_id,
+            &0,
+            &test.claim_addresses[0],
+            &300,
+            &proof
+        ),
+        Err(Ok(Error::InvalidProof.into()))
+    );
+    // Başka bir talepçinin yaprağı.
+    assert_eq!(
+        test.contract.try_claim_merkle(
+            &distribution_id,
+            &0,
+            &test.claim_addresses[1],
+            &100,
+            &proof
+        ),
+        Err(Ok(Error::InvalidProof.into()))
+    );
+    // Ağaçta olmayan bir sıra.
+    assert_eq!(
+        test.contract.try_claim_merkle(
+            &distribution_id,
+            &3,
+            &test.claim_addresses[0],
+            &100,
+            &proof
+        ),
+        Err(Ok(Error::InvalidProof.into()))
+    );
+    assert_eq!(test.token.balance(&test.contract.address), 600);
+}
+
+// Bu test, zaman koşulu sağlanmadan Merkle talebinin yapılamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "time predicate is not fulfilled")]
+fn test_merkle_out_of_time_bound_claim_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let (distribution_id, tree) = setup_merkle(&test);
+    test.env.ledger().set_timestamp(12347);
+    test.contract.claim_merkle(
+        &distribution_id,
+        &0,
+        &test.claim_addresses[0],
+        &100,
+        &tree.proof(&test.env, 0),
+    );
+}
+
+// Bu test, süre dolduktan sonra talep edilmemiş kalanın yatırana geri ödendiğini kontrol eder.
+#[test]
+fn test_merkle_reclaim_remainder_after_expiry() {
+    let test = ClaimableBalanceTest::setup();
+    let (distribution_id, tree) = setup_merkle(&test);
+    test.contract.claim_merkle(
+        &distribution_id,
+        &0,
+        &test.claim_addresses[0],
+        &100,
+        &tree.proof(&test.env, 0),
+    );
+
+    test.env.ledger().set_timestamp(12347);
+    test.contract.reclaim_merkle(&distribution_id);
+
+    assert_eq!(test.token.balance(&test.deposit_address), 900);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+    assert_eq!(test.contract.get_distribution(&distribution_id), None);
+}
+
+// Bu test, süre dolmadan kalanın geri alınamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "balance can still be claimed")]
+fn test_merkle_reclaim_before_expiry_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let (distribution_id, _) = setup_merkle(&test);
+    test.contract.reclaim_merkle(&distribution_id);
+}
+
+// Bu test, 'After' sınırlı bir Merkle dağıtımının reddedildiğini kontrol eder; böyle bir
+// dağıtımın süresi hiç dolmayacağı için talep edilmeyen paylar geri alınamazdı.
+#[test]
+fn test_merkle_deposit_with_after_bound_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let tree = MerkleTree::new(&test.env, &[(test.claim_addresses[0].clone(), 100)]);
+    assert_eq!(
+        test.contract.try_deposit_merkle(
+            &test.deposit_address,
+            &test.token.address,
+            &100,
+            &tree.root(),
+            &1,
+            &after(12346),
+        ),
+        Err(Ok(Error::InvalidTimeBound.into()))
+    );
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+}
+
+// Bu test, talepçi sınırının çok üzerindeki dağıtımların da çalıştığını kontrol eder.
+#[test]
+fn test_merkle_distribution_with_many_claimants() {
+    let test = ClaimableBalanceTest::setup();
+    let leaves: std::vec::Vec<(Address, i128)> = (0..300)
+        .map(|_| (Address::generate(&test.env), 3))
+        .collect();
+    let tree = MerkleTree::new(&test.env, &leaves);
+    let distribution_id = test.contract.deposit_merkle(
+        &test.deposit_address,
+        &test.token.address,
+        &900,
+        &tree.root(),
+        &300,
+        &before(12346),
+    );
+
+    // Farklı bit haritası kayıtlarına düşen yapraklar.
+    for index in [0, 63, 64, 299] {
+        let (claimant, amount) = &leaves[index];
+        test.contract.claim_merkle(
+            &distribution_id,
+            &(index as u32),
+            claimant,
+            amount,
+            &tree.proof(&test.env, index),
+        );
+        assert_eq!(test.token.balance(claimant), 3);
+        assert!(test.contract.is_leaf_claimed(&distribution_id, &(index as u32)));
+    }
+    assert!(!test.contract.is_leaf_claimed(&distribution_id, &65));
+    assert_eq!(test.token.balance(&test.contract.address), 888);
+}
+
+// Kupon imzalamak için sabit tohumdan (seed) üretilen ed25519 anahtarı.
+fn voucher_signer(seed: u8) -> ed25519_dalek::SigningKey {
+    ed25519_dalek::SigningKey::from_bytes(&[seed; 32])
+}
+
+// Yatıranın zincir dışında yapacağı gibi bir kupon imzalar.
+fn sign_voucher(
+    test: &ClaimableBalanceTest,
+    key: &ed25519_dalek::SigningKey,
+    balance_id: u64,
+    claimant: &Address,
+    amount: i128,
+    nonce: u64,
+) -> BytesN<64> {
+    use ed25519_dalek::Signer;
+    let message = test.env.as_contract(&test.contract.address, || {
+        voucher::voucher_message(&test.env, balance_id, claimant, amount, nonce)
+    });
+    let message: std::vec::Vec<u8> = message.iter().collect();
+    BytesN::from_array(&test.env, &key.sign(&message).to_bytes())
+}
+
+// `voucher_signer(1)` anahtarıyla talep edilen 600 tokenlık bir kuponlu bakiye oluşturur.
+fn setup_voucher(test: &ClaimableBalanceTest, kind: TimeBoundKind) -> u64 {
+    test.contract.deposit_voucher(
+        &test.deposit_address,
+        &test.token.address,
+        &600,
+        &BytesN::from_array(&test.env, &voucher_signer(1).verifying_key().to_bytes()),
+        &TimeBound {
+            kind,
+            timestamp: 12346,
+        },
+    )
+}
+
+// Bu test, 'After' sınırlı bir kupon bakiyesinin oluşturulamadığını kontrol eder.
+#[test]
+fn test_voucher_deposit_with_after_bound_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    assert_eq!(
+        test.contract.try_deposit_voucher(
+            &test.deposit_address,
+            &test.token.address,
+            &600,
+            &BytesN::from_array(&test.env, &voucher_signer(1).verifying_key().to_bytes()),
+            &after(12346),
+        ),
+        Err(Ok(Error::InvalidTimeBound.into()))
+    );
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+}
+
+// Bu test, yatıranın imzaladığı kuponlarla farklı adreslerin talep yapabildiğini kontrol eder.
+#[test]
+fn test_claim_with_voucher() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_voucher(&test, TimeBoundKind::Before);
+    let key = voucher_signer(1);
+
+    let signature = sign_voucher(&test, &key, balance_id, &test.claim_addresses[0], 250, 0);
+    test.contract.claim_with_voucher(
+        &balance_id,
+        &test.claim_addresses[0],
+        &250,
+        &0,
+        &signature,
+    );
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("claim"), test.claim_addresses[0].clone()).into_val(&test.env),
+                (balance_id, test.token.address.clone(), 250_i128, 0_i128).into_val(&test.env),
+            ),
+        ]
+    );
+    let signature = sign_voucher(&test, &key, balance_id, &test.claim_addresses[1], 100, 1);
+    test.contract.claim_with_voucher(
+        &balance_id,
+        &test.claim_addresses[1],
+        &100,
+        &1,
+        &signature,
+    );
+
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 250);
+    assert_eq!(test.token.balance(&test.claim_addresses[1]), 100);
+    assert!(test.contract.is_nonce_used(&balance_id, &0));
+    assert!(!test.contract.is_nonce_used(&balance_id, &2));
+    assert_eq!(
+        test.contract
+            .get_voucher_balance(&balance_id)
+            .unwrap()
+            .remaining,
+        250
+    );
+}
+
+// Bu test, aynı kuponun iki kez kullanılamayacağını kontrol eder.
+#[test]
+fn test_voucher_replay_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_voucher(&test, TimeBoundKind::Before);
+    let signature = sign_voucher(
+        &test,
+        &voucher_signer(1),
+        balance_id,
+        &test.claim_addresses[0],
+        250,
+        7,
+    );
+    test.contract.claim_with_voucher(
+        &balance_id,
+        &test.claim_addresses[0],
+        &250,
+        &7,
+        &signature,
+    );
+
+    assert_eq!(
+        test.contract.try_claim_with_voucher(
+            &balance_id,
+            &test.claim_addresses[0],
+            &250,
+            &7,
+            &signature
+        ),
+        Err(Ok(Error::NonceUsed.into()))
+    );
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 250);
+}
+
+// Bu test, başka bir anahtarla imzalanmış veya değiştirilmiş kuponların reddedildiğini kontrol eder.
+#[test]
+fn test_voucher_with_invalid_signature_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_voucher(&test, TimeBoundKind::Before);
+
+    // Kayıtlı olmayan bir anahtarla imzalanmış kupon.
+    let forged = sign_voucher(
+        &test,
+        &voucher_signer(2),
+        balance_id,
+        &test.claim_addresses[0],
+        250,
+        0,
+    );
+    assert!(test
+        .contract
+        .try_claim_with_voucher(&balance_id, &test.claim_addresses[0], &250, &0, &forged)
+        .is_err());
+
+    // Geçerli kupon, ancak başka bir adres veya daha yüksek bir miktar için kullanılıyor.
+    let signature = sign_voucher(
+        &test,
+        &voucher_signer(1),
+        balance_id,
+        &test.claim_addresses[0],
+        250,
+        0,
+    );
+    assert!(test
+        .contract
+        .try_claim_with_voucher(&balance_id, &test.claim_addresses[1], &250, &0, &signature)
+        .is_err());
+    assert!(test
+        .contract
+        .try_claim_with_voucher(&balance_id, &test.claim_addresses[0], &500, &0, &signature)
+        .is_err());
+
+    assert_eq!(test.token.balance(&test.contract.address), 600);
+    assert!(!test.contract.is_nonce_used(&balance_id, &0));
+}
+
+// Bu test, kalan bakiyeden büyük bir kuponun reddedildiğini kontrol eder.
+#[test]
+fn test_voucher_above_remaining_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_voucher(&test, TimeBoundKind::Before);
+    let signature = sign_voucher(
+        &test,
+        &voucher_signer(1),
+        balance_id,
+        &test.claim_addresses[0],
+        601,
+        0,
+    );
+
+    assert_eq!(
+        test.contract.try_claim_with_voucher(
+            &balance_id,
+            &test.claim_addresses[0],
+            &601,
+            &0,
+            &signature
+        ),
+        Err(Ok(Error::AmountExceedsBalance.into()))
+    );
+}
+
+// Bu test, kuponların da bakiyenin zaman sınırına uyduğunu kontrol eder.
+#[test]
+#[should_panic(expected = "time predicate is not fulfilled")]
+fn test_voucher_out_of_time_bound_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_voucher(&test, TimeBoundKind::Before);
+    let signature = sign_voucher(
+        &test,
+        &voucher_signer(1),
+        balance_id,
+        &test.claim_addresses[0],
+        250,
+        0,
+    );
+
+    test.env.ledger().set_timestamp(12347);
+    test.contract.claim_with_voucher(
+        &balance_id,
+        &test.claim_addresses[0],
+        &250,
+        &0,
+        &signature,
+    );
+}
+
+// Bu test, süre dolduktan sonra kuponlarla talep edilmeyen kalanın yatırana döndüğünü kontrol eder.
+#[test]
+fn test_voucher_balance_reclaim_after_expiry() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_voucher(&test, TimeBoundKind::Before);
+    let signature = sign_voucher(
+        &test,
+        &voucher_signer(1),
+        balance_id,
+        &test.claim_addresses[0],
+        250,
+        0,
+    );
+    test.contract.claim_with_voucher(
+        &balance_id,
+        &test.claim_addresses[0],
+        &250,
+        &0,
+        &signature,
+    );
+
+    test.env.ledger().set_timestamp(12347);
+    test.contract.reclaim_voucher_balance(&balance_id);
+
+    assert_eq!(test.token.balance(&test.deposit_address), 750);
+    assert_eq!(test.contract.get_voucher_balance(&balance_id), None);
+}
+
+// 12445 ile 12745 arasında alıcıya saniyede 2 token akan 600 tokenlık bir akış oluşturur.
+fn setup_stream(test: &ClaimableBalanceTest) -> u64 {
+    test.contract.create_stream(
+        &test.deposit_address,
+        &test.claim_addresses[0],
+        &test.token.address,
+        &600,
+        &12445,
+        &12745,
+    )
+}
+
+// Bu test, alıcının biriken miktarı parça parça çekebildiğini kontrol eder.
+#[test]
+fn test_stream_withdraw() {
+    let test = ClaimableBalanceTest::setup();
+    let stream_id = setup_stream(&test);
+    let recipient = &test.claim_addresses[0];
+
+    // Akış başlamadan çekilecek bir şey yoktur.
+    assert_eq!(test.contract.withdraw(&stream_id), 0);
+
+    test.env.ledger().set_timestamp(12595);
+    assert_eq!(test.contract.withdrawable(&stream_id), 300);
+    assert_eq!(test.contract.withdraw(&stream_id), 300);
+    assert_eq!(
+        test.env.auths()[0].0,
+        recipient.clone(),
+        "only the recipient can withdraw"
+    );
+    assert_eq!(test.token.balance(recipient), 300);
+    assert_eq!(test.contract.withdrawable(&stream_id), 0);
+
+    // Bitişten sonra kalanın tamamı çekilir ve akış kaydı silinir.
+    test.env.ledger().set_timestamp(20000);
+    assert_eq!(test.contract.withdraw(&stream_id), 300);
+    assert_eq!(test.token.balance(recipient), 600);
+    assert_eq!(test.contract.get_stream(&stream_id), None);
+}
+
+// Bu test, iptal edilen akışta birikmiş kısmın alıcıya, kalanın gönderene ödendiğini kontrol eder.
+#[test]
+fn test_stream_cancel_splits_amounts() {
+    let test = ClaimableBalanceTest::setup();
+    let stream_id = setup_stream(&test);
+    let recipient = &test.claim_addresses[0];
+
+    test.env.ledger().set_timestamp(12495);
+    test.contract.withdraw(&stream_id);
+    test.env.ledger().set_timestamp(12545);
+    test.contract.cancel_stream(&stream_id);
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("cancel"), test.deposit_address.clone()).into_val(&test.env),
+                (stream_id, test.token.address.clone(), 100_i128, 400_i128)
+                    .into_val(&test.env),
+            ),
+        ]
+    );
+
+    assert_eq!(test.token.balance(recipient), 200);
+    assert_eq!(test.token.balance(&test.deposit_address), 800);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+    assert_eq!(test.contract.get_stream(&stream_id), None);
+}
+
+// Bu test, akışı sadece gönderenin iptal edebildiğini kontrol eder.
+#[test]
+fn test_stream_cancel_requires_sender() {
+    let test = ClaimableBalanceTest::setup();
+    let stream_id = setup_stream(&test);
+
+    test.contract.cancel_stream(&stream_id);
+    assert_eq!(test.env.auths()[0].0, test.deposit_address);
+}
+
+// Bu test, geçersiz zaman planlı akışların reddedildiğini kontrol eder.
+#[test]
+fn test_stream_with_invalid_schedule_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    for (start, stop) in [(12745, 12745), (12745, 12445), (12000, 12345)] {
+        assert_eq!(
+            test.contract.try_create_stream(
+                &test.deposit_address,
+                &test.claim_addresses[0],
+                &test.token.address,
+                &600,
+                &start,
+                &stop,
+            ),
+            Err(Ok(Error::InvalidSchedule.into()))
+        );
+    }
+}
+
+// Bu test, akış hesabının bölünemeyen miktarlarda aşağı yuvarladığını ve
+// çok büyük değerlerde taşmadığını kontrol eder.
+#[test]
+fn test_stream_rate_math() {
+    let e = Env::default();
+    let address = Address::generate(&e);
+    let mut stream = Stream {
+        sender: address.clone(),
+        recipient: address.clone(),
+        token: address,
+        deposit: 10,
+        start: 100,
+        stop: 103,
+        withdrawn: 0,
+    };
+    assert_eq!(stream::streamed_amount(&stream, 100), 0);
+    assert_eq!(stream::streamed_amount(&stream, 101), 3);
+    assert_eq!(stream::streamed_amount(&stream, 102), 6);
+    assert_eq!(stream::streamed_amount(&stream, 103), 10);
+
+    stream.deposit = i128::MAX;
+    stream.start = 0;
+    stream.stop = u64::MAX - 1;
+    let half = stream::streamed_amount(&stream, stream.stop / 2);
+    assert_eq!(half, i128::MAX / 2);
+    assert!(stream::streamed_amount(&stream, stream.stop - 1) < i128::MAX);
+    assert_eq!(stream::streamed_amount(&stream, stream.stop), i128::MAX);
+}
+
+// Bir haftanın saniye cinsinden uzunluğu.
+const WEEK: u64 = 7 * 24 * 60 * 60;
+
+// 12345'ten başlayarak haftada bir 100 token açılan, 4 dönemlik bir ödeme planı oluşturur.
+fn setup_recurring(test: &ClaimableBalanceTest) -> u64 {
+    test.contract.deposit_recurring(
+        &test.deposit_address,
+        &test.claim_addresses[0],
+        &test.token.address,
+        &RecurringSchedule {
+            amount_per_period: 100,
+            period: WEEK,
+            start: 12345,
+            count: 4,
+        },
+    )
+}
+
+// Bu test, talep edilmeyen dönemlerin birikip birlikte ödendiğini kontrol eder.
+#[test]
+fn test_recurring_payout_accumulates_periods() {
+    let test = ClaimableBalanceTest::setup();
+    let payout_id = setup_recurring(&test);
+    let claimant = &test.claim_addresses[0];
+    assert_eq!(test.token.balance(&test.contract.address), 400);
+
+    // İlk dönem henüz dolmadı.
+    test.env.ledger().set_timestamp(12345 + WEEK - 1);
+    assert_eq!(
+        test.contract.try_claim_recurring(&payout_id),
+        Err(Ok(Error::NothingToClaim.into()))
+    );
+
+    test.env.ledger().set_timestamp(12345 + WEEK);
+    assert_eq!(test.contract.claim_recurring(&payout_id), 100);
+    assert_eq!(test.env.auths()[0].0, claimant.clone());
+
+    // İki dönem talep edilmeden geçer ve birlikte ödenir.
+    test.env.ledger().set_timestamp(12345 + 3 * WEEK + 10);
+    assert_eq!(test.contract.claimable_periods(&payout_id), 2);
+    assert_eq!(test.contract.claim_recurring(&payout_id), 200);
+    assert_eq!(test.token.balance(claimant), 300);
+    assert_eq!(
+        test.contract.try_claim_recurring(&payout_id),
+        Err(Ok(Error::NothingToClaim.into()))
+    );
+}
+
+// Bu test, son dönemden sonra fazladan ödeme yapılmadığını ve kaydın silindiğini kontrol eder.
+#[test]
+fn test_recurring_payout_ends_after_count() {
+    let test = ClaimableBalanceTest::setup();
+    let payout_id = setup_recurring(&test);
+
+    test.env.ledger().set_timestamp(12345 + 100 * WEEK);
+    assert_eq!(test.contract.claim_recurring(&payout_id), 400);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+    assert_eq!(test.contract.get_recurring(&payout_id), None);
+    assert_eq!(
+        test.contract.try_claim_recurring(&payout_id),
+        Err(Ok(Error::BalanceNotFound.into()))
+    );
+}
+
+// Bu test, yatırma ücreti kesildiğinde son ödemenin havuzda kalanla sınırlı olduğunu kontrol eder.
+#[test]
+fn test_recurring_payout_last_period_limited_by_pool() {
+    let test = ClaimableBalanceTest::setup();
+    test.set_fees(100, 0);
+    let payout_id = setup_recurring(&test);
+    assert_eq!(test.contract.get_recurring(&payout_id).unwrap().remaining, 396);
+
+    test.env.ledger().set_timestamp(12345 + 3 * WEEK);
+    assert_eq!(test.contract.claim_recurring(&payout_id), 300);
+    test.env.ledger().set_timestamp(12345 + 4 * WEEK);
+    assert_eq!(test.contract.claim_recurring(&payout_id), 96);
+    assert_eq!(test.contract.get_recurring(&payout_id), None);
+}
+
+// Bu test, geçersiz ödeme planlarının reddedildiğini kontrol eder.
+#[test]
+fn test_recurring_payout_with_invalid_schedule_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let schedule = RecurringSchedule {
+        amount_per_period: 100,
+        period: WEEK,
+        start: 12345,
+        count: 4,
+    };
+    let cases = [
+        (
+            RecurringSchedule {
+                period: 0,
+                ..schedule.clone()
+            },
+            Error::InvalidSchedule,
+        ),
+        (
+            RecurringSchedule {
+                count: 0,
+                ..schedule.clone()
+            },
+            Error::InvalidSchedule,
+        ),
+        (
+            RecurringSchedule {
+                amount_per_period: 0,
+                ..schedule.clone()
+            },
+            Error::InvalidAmount,
+        ),
+        (
+            RecurringSchedule {
+                amount_per_period: i128::MAX,
+                ..schedule
+            },
+            Error::InvalidAmount,
+        ),
+    ];
+    for (schedule, error) in cases {
+        assert_eq!(
+            test.contract.try_deposit_recurring(
+                &test.deposit_address,
+                &test.claim_addresses[0],
+                &test.token.address,
+                &schedule,
+            ),
+            Err(Ok(error.into()))
+        );
+    }
+}
+
+// Miras kilitlerinin testlerde kullanılan hareketsizlik süresi (30 gün).
+const INACTIVITY_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+// İlk iki talepçiyi mirasçı yapan 600 tokenlık bir miras kilidi oluşturur.
+fn setup_inheritance(test: &ClaimableBalanceTest) -> u64 {
+    test.contract.deposit_inheritance(
+        &test.deposit_address,
+        &test.token.address,
+        &600,
+        &test.claimants(&[0, 1]),
+        &INACTIVITY_WINDOW,
+    )
+}
+
+// Bu test, sahip düzenli olarak `heartbeat` çağırdığı sürece mirasçıların talep edemediğini kontrol eder.
+#[test]
+fn test_inheritance_heartbeat_keeps_lock() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_inheritance(&test);
+    let heir = &test.claim_addresses[0];
+
+    // Süre dolmadan hemen önce sahip etkin olduğunu bildirir.
+    test.env
+        .ledger()
+        .set_timestamp(12345 + INACTIVITY_WINDOW - 1);
+    test.contract.heartbeat(&balance_id);
+    assert_eq!(test.env.auths()[0].0, test.deposit_address);
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (Symbol::new(&test.env, "heartbeat"), test.deposit_address.clone())
+                    .into_val(&test.env),
+                (balance_id, 12345 + 2 * INACTIVITY_WINDOW - 1).into_val(&test.env),
+            ),
+        ]
+    );
+
+    // İlk son tarih geçti, ancak heartbeat onu erteledi.
+    test.env
+        .ledger()
+        .set_timestamp(12345 + INACTIVITY_WINDOW + 1);
+    assert!(test.contract.try_claim(&balance_id, heir).is_err());
+    assert_eq!(test.token.balance(&test.contract.address), 600);
+}
+
+// Bu test, sahip heartbeat'i kaçırırsa mirasçıların bakiyeyi talep edebildiğini kontrol eder.
+#[test]
+fn test_inheritance_claim_after_missed_heartbeat() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_inheritance(&test);
+    let heir = &test.claim_addresses[1];
+
+    test.env.ledger().set_timestamp(12345 + 10);
+    test.contract.heartbeat(&balance_id);
+    test.env
+        .ledger()
+        .set_timestamp(12345 + 10 + INACTIVITY_WINDOW);
+    test.contract.claim(&balance_id, heir);
+
+    assert_eq!(test.token.balance(heir), 600);
+    assert_eq!(test.contract.inactivity_window(&balance_id), None);
+}
+
+// Bu test, sahibin istediği zaman çekim yapabildiğini ve çekimin süreyi yenilediğini kontrol eder.
+#[test]
+fn test_inheritance_owner_withdraw() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_inheritance(&test);
+
+    test.env.ledger().set_timestamp(12345 + INACTIVITY_WINDOW);
+    test.contract.withdraw_inheritance(&balance_id, &200);
+    let balance = test.contract.get_balance(&balance_id).unwrap();
+    assert_eq!(balance.amount, 400);
+    assert_eq!(balance.time_bound.timestamp, 12345 + 2 * INACTIVITY_WINDOW);
+    assert_eq!(test.token.balance(&test.deposit_address), 600);
+
+    assert_eq!(
+        test.contract.try_withdraw_inheritance(&balance_id, &401),
+        Err(Ok(Error::AmountExceedsBalance.into()))
+    );
+    test.contract.withdraw_inheritance(&balance_id, &400);
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+    assert_eq!(test.contract.get_balance(&balance_id), None);
+    assert_eq!(test.contract.inactivity_window(&balance_id), None);
+}
+
+// Bu test, heartbeat'in sadece miras kilitlerinde kullanılabildiğini kontrol eder.
+#[test]
+fn test_heartbeat_on_regular_balance_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(600, &test.claimants(&[0]), &after(20000));
+
+    assert_eq!(
+        test.contract.try_heartbeat(&balance_id),
+        Err(Ok(Error::NotInheritanceLock.into()))
+    );
+    assert_eq!(
+        test.contract.try_withdraw_inheritance(&balance_id, &600),
+        Err(Ok(Error::NotInheritanceLock.into()))
+    );
+}
+
+// Birinci kademesi 12445'e, ikinci kademesi 12545'e kadar talep edebilen 600 tokenlık bir bakiye oluşturur.
+fn setup_tiered(test: &ClaimableBalanceTest) -> u64 {
+    test.contract.deposit_tiered(
+        &test.deposit_address,
+        &test.token.address,
+        &600,
+        &vec![
+            &test.env,
+            ClaimantTier {
+                claimants: test.claimants(&[0]),
+                deadline: 12445,
+            },
+            ClaimantTier {
+                claimants: test.claimants(&[1, 2]),
+                deadline: 12545,
+            },
+        ],
+    )
+}
+
+// Bu test, birinci kademe son tarihine kadar sadece birinci kademenin talep edebildiğini kontrol eder.
+#[test]
+fn test_tiered_primary_claims_first() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_tiered(&test);
+
+    test.env.ledger().set_timestamp(12445);
+    assert_eq!(
+        test.contract
+            .try_claim(&balance_id, &test.claim_addresses[1]),
+        Err(Ok(Error::TierNotActive.into()))
+    );
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 600);
+}
+
+// Bu test, birinci kademenin süresi dolunca ikinci kademenin talep edebildiğini kontrol eder.
+#[test]
+fn test_tiered_fallback_claims_after_primary_deadline() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_tiered(&test);
+
+    test.env.ledger().set_timestamp(12446);
+    assert_eq!(
+        test.contract
+            .try_claim(&balance_id, &test.claim_addresses[0]),
+        Err(Ok(Error::TierNotActive.into()))
+    );
+    test.env.ledger().set_timestamp(12545);
+    test.contract.claim(&balance_id, &test.claim_addresses[2]);
+    assert_eq!(test.token.balance(&test.claim_addresses[2]), 600);
+    assert_eq!(test.contract.get_tiers(&balance_id), None);
+}
+
+// Bu test, son kademenin de süresi dolunca bakiyenin yatırana döndüğünü kontrol eder.
+#[test]
+fn test_tiered_reclaim_after_last_deadline() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_tiered(&test);
+
+    test.env.ledger().set_timestamp(12545);
+    assert!(test.contract.try_reclaim(&balance_id).is_err());
+    test.env.ledger().set_timestamp(12546);
+    assert!(test
+        .contract
+        .try_claim(&balance_id, &test.claim_addresses[1])
+        .is_err());
+    test.contract.reclaim(&balance_id);
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+}
+
+// Bu test, geçersiz kademe tanımlarının reddedildiğini kontrol eder.
+#[test]
+fn test_tiered_with_invalid_tiers_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let tier = |claimants: Vec<Address>, deadline: u64| ClaimantTier {
+        claimants,
+        deadline,
+    };
+    let primary = test.claimants(&[0]);
+    let secondary = test.claimants(&[1]);
+    let cases = [
+        (
+            vec![
+                &test.env,
+                tier(primary.clone(), 12545),
+                tier(secondary.clone(), 12545),
+            ],
+            Error::InvalidSchedule,
+        ),
+        (
+            vec![
+                &test.env,
+                tier(primary.clone(), 12445),
+                tier(Vec::new(&test.env), 12545),
+            ],
+            Error::NoClaimants,
+        ),
+        (
+            vec![
+                &test.env,
+                tier(primary.clone(), 12445),
+                tier(primary, 12545),
+            ],
+            Error::DuplicateClaimant,
+        ),
+        (Vec::new(&test.env), Error::NoClaimants),
+    ];
+    for (tiers, error) in cases {
+        assert_eq!(
+            test.contract.try_deposit_tiered(
+                &test.deposit_address,
+                &test.token.address,
+                &600,
+                &tiers
+            ),
+            Err(Ok(error.into()))
+        );
+    }
+}
+
+// Alıcısı yatıran adres, satıcısı ilk talepçi, hakemi ikinci talepçi olan ve 12445'ten
+// itibaren serbest bırakılabilen 600 tokenlık bir emanet oluşturur.
+fn setup_escrow(test: &ClaimableBalanceTest) -> u64 {
+    test.contract.create_escrow(
+        &test.deposit_address,
+        &test.claim_addresses[0],
+        &test.claim_addresses[1],
+        &test.token.address,
+        &600,
+        &12445,
+    )
+}
+
+// Bu test, alıcı onayladığında token'ların hemen satıcıya ödendiğini kontrol eder.
+#[test]
+fn test_escrow_confirmed_by_buyer() {
+    let test = ClaimableBalanceTest::setup();
+    let escrow_id = setup_escrow(&test);
+    assert_eq!(
+        test.contract.get_escrow(&escrow_id).unwrap().state,
+        EscrowState::Funded
+    );
+
+    test.contract.confirm_escrow(&escrow_id);
+    assert_eq!(test.env.auths()[0].0, test.deposit_address);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 600);
+    assert_eq!(
+        test.contract.get_escrow(&escrow_id).unwrap().state,
+        EscrowState::Released
+    );
+    assert_eq!(
+        test.contract.try_confirm_escrow(&escrow_id),
+        Err(Ok(Error::InvalidEscrowState.into()))
+    );
+}
+
+// Bu test, anlaşmazlık yoksa satıcının zaman aşımından sonra token'ları alabildiğini kontrol eder.
+#[test]
+fn test_escrow_released_after_timeout() {
+    let test = ClaimableBalanceTest::setup();
+    let escrow_id = setup_escrow(&test);
+
+    test.env.ledger().set_timestamp(12444);
+    assert!(test.contract.try_release_escrow(&escrow_id).is_err());
+    test.env.ledger().set_timestamp(12445);
+    test.contract.release_escrow(&escrow_id);
+    assert_eq!(test.env.auths()[0].0, test.claim_addresses[0]);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 600);
+}
+
+// Bu test, anlaşmazlığın otomatik serbest bırakmayı durdurduğunu ve hakemin kararının uygulandığını kontrol eder.
+#[test]
+fn test_escrow_dispute_resolved_by_arbiter() {
+    let test = ClaimableBalanceTest::setup();
+    let escrow_id = setup_escrow(&test);
+    let arbiter = &test.claim_addresses[1];
+
+    test.contract.dispute_escrow(&escrow_id, &test.deposit_address);
+    test.env.ledger().set_timestamp(12445);
+    assert_eq!(
+        test.contract.try_release_escrow(&escrow_id),
+        Err(Ok(Error::InvalidEscrowState.into()))
+    );
+    assert_eq!(
+        test.contract.try_resolve_escrow(&escrow_id, &601),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+
+    test.contract.resolve_escrow(&escrow_id, &450);
+    assert_eq!(test.env.auths()[0].0, arbiter.clone());
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("escrow"), arbiter.clone()).into_val(&test.env),
+                (escrow_id, EscrowState::Resolved, 450_i128, 150_i128, 0_i128)
+                    .into_val(&test.env),
+            ),
+        ]
+    );
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 450);
+    assert_eq!(test.token.balance(&test.deposit_address), 550);
+}
+
+// Bu test, hakemin tamamını alıcıya verdiğinde emanetin iade edilmiş sayıldığını kontrol eder.
+#[test]
+fn test_escrow_dispute_refunded_by_arbiter() {
+    let test = ClaimableBalanceTest::setup();
+    let escrow_id = setup_escrow(&test);
+
+    test.contract.dispute_escrow(&escrow_id, &test.claim_addresses[0]);
+    test.contract.resolve_escrow(&escrow_id, &0);
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+    assert_eq!(
+        test.contract.get_escrow(&escrow_id).unwrap().state,
+        EscrowState::Refunded
+    );
+}
+
+// Bu test, satıcının token'ları alıcıya iade edebildiğini kontrol eder.
+#[test]
+fn test_escrow_refunded_by_seller() {
+    let test = ClaimableBalanceTest::setup();
+    let escrow_id = setup_escrow(&test);
+
+    test.contract.refund_escrow(&escrow_id);
+    assert_eq!(test.env.auths()[0].0, test.claim_addresses[0]);
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+    assert_eq!(
+        test.contract.get_escrow(&escrow_id).unwrap().state,
+        EscrowState::Refunded
+    );
+}
+
+// Bu test, zaman aşımı dolduktan sonra anlaşmazlık açılamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "escrow can already be released")]
+fn test_escrow_dispute_after_timeout_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let escrow_id = setup_escrow(&test);
+
+    test.env.ledger().set_timestamp(12445);
+    test.contract.dispute_escrow(&escrow_id, &test.deposit_address);
+}
+
+// Bu test, taraf olmayan bir adresin anlaşmazlık açamayacağını kontrol eder.
+#[test]
+#[should_panic(expected = "only the buyer or the seller can dispute")]
+fn test_escrow_dispute_by_outsider_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let escrow_id = setup_escrow(&test);
+
+    test.contract.dispute_escrow(&escrow_id, &test.claim_addresses[2]);
+}
+
+// Bu test, hakem süresi içinde karar vermezse alıcının token'ları geri alabildiğini ve
+// sürenin dolmasından sonra hakemin karar veremeyeceğini kontrol eder.
+#[test]
+fn test_escrow_dispute_refunded_after_arbitration_period() {
+    let test = ClaimableBalanceTest::setup();
+    let escrow_id = setup_escrow(&test);
+    test.contract.dispute_escrow(&escrow_id, &test.claim_addresses[0]);
+    let resolve_before = 12345 + escrow::ARBITRATION_PERIOD;
+    assert_eq!(
+        test.contract.get_escrow(&escrow_id).unwrap().resolve_before,
+        Some(resolve_before)
+    );
+
+    test.env.ledger().set_timestamp(resolve_before - 1);
+    assert!(test.contract.try_expire_dispute(&escrow_id).is_err());
+    test.env.ledger().set_timestamp(resolve_before);
+    assert!(test.contract.try_resolve_escrow(&escrow_id, &600).is_err());
+
+    test.contract.expire_dispute(&escrow_id);
+    assert_eq!(test.env.auths()[0].0, test.deposit_address);
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+    assert_eq!(
+        test.contract.get_escrow(&escrow_id).unwrap().state,
+        EscrowState::Refunded
+    );
+}
+
+// Bu test, anlaşmazlık açılmamış bir emanette hakem süresi dolmuş sayılamayacağını kontrol eder.
+#[test]
+fn test_escrow_expire_without_dispute_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let escrow_id = setup_escrow(&test);
+
+    test.env.ledger().set_timestamp(12345 + escrow::ARBITRATION_PERIOD);
+    assert_eq!(
+        test.contract.try_expire_dispute(&escrow_id),
+        Err(Ok(Error::InvalidEscrowState.into()))
+    );
+}
+
+// Bu test, zaman aşımı geçmişte olan bir emanetin oluşturulamayacağını kontrol eder.
+#[test]
+fn test_escrow_with_past_release_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    for release_after in [12344, 12345] {
+        assert_eq!(
+            test.contract.try_create_escrow(
+                &test.deposit_address,
+                &test.claim_addresses[0],
+                &test.claim_addresses[1],
+                &test.token.address,
+                &600,
+                &release_after,
+            ),
+            Err(Ok(Error::InvalidSchedule.into()))
+        );
+    }
+}
+
+// Bu test, hakemin alıcı veya satıcı olamayacağını kontrol eder.
+#[test]
+fn test_escrow_with_party_as_arbiter_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let seller = &test.claim_addresses[0];
+    for arbiter in [&test.deposit_address, seller] {
+        assert_eq!(
+            test.contract.try_create_escrow(
+                &test.deposit_address,
+                seller,
+                arbiter,
+                &test.token.address,
+                &600,
+                &12445,
+            ),
+            Err(Ok(Error::InvalidArbiter.into()))
+        );
+    }
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+}
+
+// İlk talepçi lehine 100, 200 ve 300 tokenlık üç aşamalı bir bakiye oluşturur.
+// İkinci aşama 12445'te otomatik onaylanır.
+fn setup_milestones(test: &ClaimableBalanceTest) -> u64 {
+    test.contract.deposit_milestones(
+        &test.deposit_address,
+        &test.claim_addresses[0],
+        &test.token.address,
+        &vec![
+            &test.env,
+            MilestoneTerms {
+                amount: 100,
+                auto_approve_at: None,
+            },
+            MilestoneTerms {
+                amount: 200,
+                auto_approve_at: Some(12445),
+            },
+            MilestoneTerms {
+                amount: 300,
+                auto_approve_at: None,
+            },
+        ],
+    )
+}
+
+// Bu test, lehtarın sadece yatıranın onayladığı aşamaları talep edebildiğini kontrol eder.
+#[test]
+fn test_milestone_claim_after_approval() {
+    let test = ClaimableBalanceTest::setup();
+    let grant_id = setup_milestones(&test);
+    let beneficiary = &test.claim_addresses[0];
+    assert_eq!(test.token.balance(&test.contract.address), 600);
+    assert_eq!(
+        test.contract.try_claim_milestones(&grant_id),
+        Err(Ok(Error::NothingToClaim.into()))
+    );
+
+    test.contract.approve_milestone(&grant_id, &0);
+    assert_eq!(test.env.auths()[0].0, test.deposit_address);
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("approved"), test.deposit_address.clone()).into_val(&test.env),
+                (grant_id, 0_u32).into_val(&test.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        test.contract.try_approve_milestone(&grant_id, &0),
+        Err(Ok(Error::InvalidMilestone.into()))
+    );
+    assert_eq!(
+        test.contract.try_approve_milestone(&grant_id, &3),
+        Err(Ok(Error::InvalidMilestone.into()))
+    );
+
+    assert_eq!(test.contract.claim_milestones(&grant_id), 100);
+    assert_eq!(test.env.auths()[0].0, beneficiary.clone());
+    assert_eq!(test.token.balance(beneficiary), 100);
+    assert_eq!(
+        test.contract.try_claim_milestones(&grant_id),
+        Err(Ok(Error::NothingToClaim.into()))
+    );
+}
+
+// Bu test, yatıran sessiz kalırsa aşamanın son tarihte otomatik onaylandığını kontrol eder.
+#[test]
+fn test_milestone_auto_approved_after_deadline() {
+    let test = ClaimableBalanceTest::setup();
+    let grant_id = setup_milestones(&test);
+
+    test.env.ledger().set_timestamp(12444);
+    assert_eq!(
+        test.contract.try_claim_milestones(&grant_id),
+        Err(Ok(Error::NothingToClaim.into()))
+    );
+    test.env.ledger().set_timestamp(12445);
+    test.contract.approve_milestone(&grant_id, &2);
+    assert_eq!(test.contract.claim_milestones(&grant_id), 500);
+
+    let grant = test.contract.get_milestones(&grant_id).unwrap();
+    assert_eq!(grant.remaining, 100);
+    assert_eq!(
+        grant.milestones.get(1).unwrap().status,
+        MilestoneStatus::Claimed
+    );
+    assert_eq!(
+        grant.milestones.get(0).unwrap().status,
+        MilestoneStatus::Pending
+    );
+}
+
+// Bu test, yatıranın onaylanmamış bir aşamayı iptal edip miktarını geri alabildiğini kontrol eder.
+#[test]
+fn test_milestone_cancel_returns_amount() {
+    let test = ClaimableBalanceTest::setup();
+    let grant_id = setup_milestones(&test);
+
+    test.contract.cancel_milestone(&grant_id, &2);
+    assert_eq!(test.token.balance(&test.deposit_address), 700);
+
+    // Otomatik onay zamanı gelmiş bir aşama artık iptal edilemez.
+    test.env.ledger().set_timestamp(12445);
+    assert_eq!(
+        test.contract.try_cancel_milestone(&grant_id, &1),
+        Err(Ok(Error::InvalidMilestone.into()))
+    );
+
+    test.contract.cancel_milestone(&grant_id, &0);
+    test.contract.claim_milestones(&grant_id);
+    assert_eq!(test.token.balance(&test.deposit_address), 800);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 200);
+    // Tüm aşamalar kapandığında kayıt silinir.
+    assert_eq!(test.contract.get_milestones(&grant_id), None);
+}
+
+// Bu test, geçersiz aşama listelerinin reddedildiğini kontrol eder.
+#[test]
+fn test_milestones_with_invalid_terms_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let terms = |amount| MilestoneTerms {
+        amount,
+        auto_approve_at: None,
+    };
+    let mut too_many = Vec::new(&test.env);
+    for _ in 0..=milestones::MAX_MILESTONES {
+        too_many.push_back(terms(1));
+    }
+    let cases = [
+        (Vec::new(&test.env), Error::InvalidSchedule),
+        (too_many, Error::InvalidSchedule),
+        (vec![&test.env, terms(100), terms(0)], Error::InvalidAmount),
+        (vec![&test.env, terms(i128::MAX), terms(1)], Error::InvalidAmount),
+    ];
+    for (terms, error) in cases {
+        assert_eq!(
+            test.contract.try_deposit_milestones(
+                &test.deposit_address,
+                &test.claim_addresses[0],
+                &test.token.address,
+                &terms,
+            ),
+            Err(Ok(error.into()))
+        );
+    }
+}
+
+// Testlerde kullanılan, fiyatı elle ayarlanan sahte fiyat kâhini.
+mod oracle {
+    use crate::{Asset, Oracle, PriceData};
+    use soroban_sdk::{contract, contractimpl, Env};
+
+    #[contract]
+    pub struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, asset: Asset, price: i128, timestamp: u64) {
+            env.storage()
+                .persistent()
+                .set(&asset, &PriceData { price, timestamp });
+        }
+    }
+
+    #[contractimpl]
+    impl Oracle for MockOracle {
+        fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+            env.storage().persistent().get(&asset)
+        }
+    }
+}
+
+// Sahte bir kâhin deploy eder ve en fazla 300 saniye eski fiyatı 5000 eşiğiyle `comparison`
+// şeklinde karşılaştıran koşula bağlı 600 tokenlık bir bakiye oluşturur.
+fn setup_price_condition<'a>(
+    test: &ClaimableBalanceTest,
+    comparison: Comparison,
+) -> (u64, oracle::MockOracleClient<'a>, Asset) {
+    let oracle = oracle::MockOracleClient::new(
+        &test.env,
+        &test.env.register(oracle::MockOracle, ()),
+    );
+    let asset = Asset::Other(symbol_short!("XLM"));
+    let balance_id = test.contract.deposit_conditional(
+        &test.deposit_address,
+        &test.token.address,
+        &600,
+        &test.claimants(&[0]),
+        &after(0),
+        &Condition::Price(PriceCondition {
+            oracle: oracle.address.clone(),
+            asset: asset.clone(),
+            comparison,
+            threshold: 5000,
+            max_age: 300,
+        }),
+    );
+    (balance_id, oracle, asset)
+}
+
+// Bu test, fiyat eşiği aşınca bakiyenin talep edilebildiğini kontrol eder.
+#[test]
+fn test_price_condition_above_threshold() {
+    let test = ClaimableBalanceTest::setup();
+    let (balance_id, oracle, asset) = setup_price_condition(&test, Comparison::Above);
+    let claimant = &test.claim_addresses[0];
+
+    oracle.set_price(&asset, &5000, &12345);
+    assert_eq!(
+        test.contract.try_claim(&balance_id, claimant),
+        Err(Ok(Error::ConditionNotMet.into()))
+    );
+    oracle.set_price(&asset, &5001, &12345);
+    test.contract.claim(&balance_id, claimant);
+    assert_eq!(test.token.balance(claimant), 600);
+    assert_eq!(test.contract.get_condition(&balance_id), None);
+}
+
+// Bu test, "altında" koşulunun fiyat eşiğin altına inince sağlandığını kontrol eder.
+#[test]
+fn test_price_condition_below_threshold() {
+    let test = ClaimableBalanceTest::setup();
+    let (balance_id, oracle, asset) = setup_price_condition(&test, Comparison::Below);
+    let claimant = &test.claim_addresses[0];
+
+    oracle.set_price(&asset, &5001, &12345);
+    assert_eq!(
+        test.contract.try_claim(&balance_id, claimant),
+        Err(Ok(Error::ConditionNotMet.into()))
+    );
+    oracle.set_price(&asset, &4999, &12345);
+    test.contract.claim(&balance_id, claimant);
+    assert_eq!(test.token.balance(claimant), 600);
+}
+
+// Bu test, eski veya hiç bildirilmemiş fiyatlarla talep yapılamayacağını kontrol eder.
+#[test]
+fn test_price_condition_stale_price_not_accepted() {
+    let test = ClaimableBalanceTest::setup();
+    let (balance_id, oracle, asset) = setup_price_condition(&test, Comparison::Above);
+    let claimant = &test.claim_addresses[0];
+
+    assert_eq!(
+        test.contract.try_claim(&balance_id, claimant),
+        Err(Ok(Error::StalePrice.into()))
+    );
+    oracle.set_price(&asset, &9000, &12044);
+    assert_eq!(
+        test.contract.try_claim(&balance_id, claimant),
+        Err(Ok(Error::StalePrice.into()))
+    );
+    // Tam `max_age` saniye eski bir fiyat hâlâ kabul edilir.
+    oracle.set_price(&asset, &9000, &12045);
+    test.contract.claim(&balance_id, claimant);
+    assert_eq!(test.token.balance(claimant), 600);
+}
+
+// İlk talepçiye 12346'ya kadar açık bir A bakiyesi ve ikinci talepçiye A kapanınca açılan
+// bir B bakiyesi oluşturur; ikisi de 400 tokendır.
+fn setup_dependency(test: &ClaimableBalanceTest) -> (u64, u64) {
+    let first = test.deposit(400, &test.claimants(&[0]), &before(12346));
+    let second = test.contract.deposit_conditional(
+        &test.deposit_address,
+        &test.token.address,
+        &400,
+        &test.claimants(&[1]),
+        &after(0),
+        &Condition::Dependency(first),
+    );
+    (first, second)
+}
+
+// Bu test, bağımlı bakiyenin bağlı olduğu bakiye talep edilince açıldığını kontrol eder.
+#[test]
+fn test_dependency_unlocks_after_claim() {
+    let test = ClaimableBalanceTest::setup();
+    let (first, second) = setup_dependency(&test);
+
+    assert_eq!(
+        test.contract
+            .try_claim(&second, &test.claim_addresses[1]),
+        Err(Ok(Error::ConditionNotMet.into()))
+    );
+    test.contract.claim(&first, &test.claim_addresses[0]);
+    assert_eq!(
+        test.contract.get_outcome(&first),
+        Some(BalanceOutcome::Claimed)
+    );
+    test.contract.claim(&second, &test.claim_addresses[1]);
+    assert_eq!(test.token.balance(&test.claim_addresses[1]), 400);
+}
+
+// Bu test, bağlı olunan bakiye iptal edilip yatırana döndüğünde de bağımlı bakiyenin açıldığını kontrol eder.
+#[test]
+fn test_dependency_unlocks_after_reclaim() {
+    let test = ClaimableBalanceTest::setup();
+    let (first, second) = setup_dependency(&test);
+
+    test.env.ledger().set_timestamp(12347);
+    test.contract.reclaim(&first);
+    assert_eq!(
+        test.contract.get_outcome(&first),
+        Some(BalanceOutcome::Reclaimed)
+    );
+    test.contract.claim(&second, &test.claim_addresses[1]);
+    assert_eq!(test.token.balance(&test.claim_addresses[1]), 400);
+    assert_eq!(test.token.balance(&test.deposit_address), 600);
+}
+
+// Bu test, var olmayan, henüz oluşturulmamış veya kapanmış bakiyelere bağımlılık
+// kurulamayacağını kontrol eder. Bağımlılık her zaman daha önce oluşturulmuş açık bir
+// bakiyeye kurulabildiği için döngü oluşamaz.
+#[test]
+fn test_dependency_on_invalid_balance_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let (first, second) = setup_dependency(&test);
+    test.contract.claim(&first, &test.claim_addresses[0]);
+
+    // Sıradaki kimlik, yani bakiyenin kendisi.
+    let next_id = second + 1;
+    for dependency in [next_id, 99, first] {
+        assert_eq!(
+            test.contract.try_deposit_conditional(
+                &test.deposit_address,
+                &test.token.address,
+                &100,
+                &test.claimants(&[2]),
+                &after(0),
+                &Condition::Dependency(dependency),
+            ),
+            Err(Ok(Error::InvalidDependency.into()))
+        );
+    }
+
+    // Açık bir bakiyeye bağımlılık kurulabilir.
+    test.contract.deposit_conditional(
+        &test.deposit_address,
+        &test.token.address,
+        &100,
+        &test.claimants(&[2]),
+        &after(0),
+        &Condition::Dependency(second),
+    );
+}
+
+// İkinci talepçiye 50 yönetişim token'ı veren ve bu token'dan en az 100 tutan herkesin
+// talep edebileceği (talepçi listesi boş) bir bakiye oluşturur.
+fn setup_holding(test: &ClaimableBalanceTest) -> (u64, TokenAdminClient<'static>) {
+    let (governance, governance_admin) = create_token_contract(&test.env, &test.admin);
+    governance_admin.mint(&test.claim_addresses[1], &50);
+    let balance_id = test.contract.deposit_conditional(
+        &test.deposit_address,
+        &test.token.address,
+        &400,
+        &Vec::new(&test.env),
+        &after(12346),
+        &Condition::Holding(HoldingCondition {
+            token: governance.address,
+            min_balance: 100,
+        }),
+    );
+    (balance_id, governance_admin)
+}
+
+// Bu test, sahiplik koşulunun talep anındaki bakiyeye göre değerlendirildiğini ve zaman
+// sınırıyla birlikte uygulandığını kontrol eder.
+#[test]
+fn test_holding_condition() {
+    let test = ClaimableBalanceTest::setup();
+    let (balance_id, governance_admin) = setup_holding(&test);
+
+    // Zaman sınırı henüz dolmadı.
+    governance_admin.mint(&test.claim_addresses[0], &100);
+    assert!(test
+        .contract
+        .try_claim(&balance_id, &test.claim_addresses[0])
+        .is_err());
+
+    test.env.ledger().set_timestamp(12346);
+    // Yeterince tutmayan adres talep edemez.
+    assert_eq!(
+        test.contract
+            .try_claim(&balance_id, &test.claim_addresses[1]),
+        Err(Ok(Error::ConditionNotMet.into()))
+    );
+    // Tam olarak en az miktarı tutan adres talep edebilir.
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 400);
+}
+
+// Bu test, talepçi listesi verilmişse sahiplik koşulunun listeye ek olarak uygulandığını ve
+// geçersiz koşulların reddedildiğini kontrol eder.
+#[test]
+fn test_holding_condition_with_claimants() {
+    let test = ClaimableBalanceTest::setup();
+    let (governance, governance_admin) = create_token_contract(&test.env, &test.admin);
+    governance_admin.mint(&test.claim_addresses[0], &100);
+    governance_admin.mint(&test.claim_addresses[1], &100);
+    let time_bound = after(0);
+    let claimants = test.claimants(&[1]);
+
+    assert_eq!(
+        test.contract.try_deposit_conditional(
+            &test.deposit_address,
+            &test.token.address,
+            &400,
+            &claimants,
+            &time_bound,
+            &Condition::Holding(HoldingCondition {
+                token: governance.address.clone(),
+                min_balance: 0,
+            }),
+        ),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    let balance_id = test.contract.deposit_conditional(
+        &test.deposit_address,
+        &test.token.address,
+        &400,
+        &claimants,
+        &time_bound,
+        &Condition::Holding(HoldingCondition {
+            token: governance.address,
+            min_balance: 100,
+        }),
+    );
+
+    // Token'ı tutsa da listede olmayan adres talep edemez.
+    assert!(test
+        .contract
+        .try_claim(&balance_id, &test.claim_addresses[0])
+        .is_err());
+    test.contract.claim(&balance_id, &test.claim_addresses[1]);
+    assert_eq!(test.token.balance(&test.claim_addresses[1]), 400);
+}
+
+// Bu test, boş talepçi listesine sadece sahiplik koşulunda izin verildiğini kontrol eder.
+#[test]
+fn test_empty_claimants_need_holding_condition() {
+    let test = ClaimableBalanceTest::setup();
+    let (first, _) = setup_dependency(&test);
+
+    assert_eq!(
+        test.contract.try_deposit_conditional(
+            &test.deposit_address,
+            &test.token.address,
+            &100,
+            &Vec::new(&test.env),
+            &after(0),
+            &Condition::Dependency(first),
+        ),
+        Err(Ok(Error::NoClaimants.into()))
+    );
+}
+
+// Bu test, sahiplik koşulu olmayan boş talepçi listeli bir bakiyenin (ör. eski bir şemadan
+// taşınmış bir kayıt) kimse tarafından talep edilemediğini kontrol eder.
+#[test]
+fn test_empty_claimants_without_holding_condition_not_claimable() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.contract.deposit(
+        &test.deposit_address,
+        &test.token.address,
+        &400,
+        &test.claimants(&[0]),
+        &after(0),
+    );
+    test.env.as_contract(&test.contract.address, || {
+        let mut balance = read_balance(&test.env, balance_id).unwrap();
+        balance.claimants = Vec::new(&test.env);
+        write_balance(&test.env, balance_id, &balance);
+    });
+
+    let stranger = Address::generate(&test.env);
+    assert!(test.contract.try_claim(&balance_id, &stranger).is_err());
+    assert!(test
+        .contract
+        .try_claim(&balance_id, &test.claim_addresses[0])
+        .is_err());
+    assert_eq!(test.token.balance(&test.contract.address), 400);
+}
+
+mod registry {
+    use crate::Registry;
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct MockRegistry;
+
+    #[contractimpl]
+    impl MockRegistry {
+        pub fn set_allowed(env: Env, address: Address, allowed: bool) {
+            env.storage().persistent().set(&address, &allowed);
+        }
+    }
+
+    #[contractimpl]
+    impl Registry for MockRegistry {
+        fn is_allowed(env: Env, address: Address) -> bool {
+            env.storage().persistent().get(&address).unwrap_or(false)
+        }
+    }
+}
+
+// Bu test, kayıt kontratının izin vermediği yatıranın bakiye oluşturamadığını kontrol eder.
+#[test]
+fn test_registry_gates_deposit() {
+    let test = ClaimableBalanceTest::setup();
+    let registry = test.set_registry();
+    let claimants = test.claimants(&[0]);
+    let time_bound = after(0);
+
+    assert_eq!(
+        test.contract.try_deposit(
+            &test.deposit_address,
+            &test.token.address,
+            &800,
+            &claimants,
+            &time_bound,
+        ),
+        Err(Ok(Error::NotAllowed.into()))
+    );
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+
+    registry.set_allowed(&test.deposit_address, &true);
+    test.deposit(800, &claimants, &time_bound);
+    assert_eq!(test.token.balance(&test.deposit_address), 200);
+}
+
+// Bu test, kayıt kontratının izin vermediği talepçinin talep edemediğini ve kaydın
+// kaldırılınca kontrolün kapandığını kontrol eder.
+#[test]
+fn test_registry_gates_claim() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(800, &test.claimants(&[0, 1]), &after(0));
+    let registry = test.set_registry();
+    registry.set_allowed(&test.claim_addresses[1], &false);
+
+    assert_eq!(
+        test.contract
+            .try_claim(&balance_id, &test.claim_addresses[1]),
+        Err(Ok(Error::NotAllowed.into()))
+    );
+
+    test.contract.set_registry(&None);
+    assert_eq!(test.contract.registry(), None);
+    test.contract.claim(&balance_id, &test.claim_addresses[1]);
+    assert_eq!(test.token.balance(&test.claim_addresses[1]), 800);
+}
+
+// Bu test, kayıt kontratının izin vermediği yatıranın hiçbir yatırma yolundan token
+// yatıramadığını ve kontrolün token'lar çekilmeden önce yapıldığını kontrol eder.
+#[test]
+fn test_registry_gates_every_deposit_path() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = test.deposit(100, &test.claimants(&[0]), &after(13345));
+    let operator = test.claim_addresses[2].clone();
+    test.contract
+        .set_operator(&test.deposit_address, &operator, &true);
+    test.token
+        .approve(&test.deposit_address, &test.contract.address, &500, &1000);
+    test.set_registry();
+    let not_allowed = Some(Ok(Error::NotAllowed.into()));
+    let (from, token) = (&test.deposit_address, &test.token.address);
+    let claimants = test.claimants(&[0]);
+
+    assert_eq!(
+        test.contract.try_top_up(&balance_id, from, &100).err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract
+            .try_deposit_from_allowance(&operator, from, token, &100, &claimants, &after(0))
+            .err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract
+            .try_batch_deposit(from, token, &lock_specs(&test, 2))
+            .err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract
+            .try_deposit_conditional(
+                from,
+                token,
+                &100,
+                &claimants,
+                &after(0),
+                &Condition::Dependency(balance_id),
+            )
+            .err(),
+        not_allowed
+    );
+    let tiers = vec![
+        &test.env,
+        ClaimantTier {
+            claimants: claimants.clone(),
+            deadline: 12445,
+        },
+    ];
+    assert_eq!(
+        test.contract
+            .try_deposit_tiered(from, token, &100, &tiers)
+            .err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract
+            .try_deposit_inheritance(from, token, &100, &claimants, &INACTIVITY_WINDOW)
+            .err(),
+        not_allowed
+    );
+    let tree = MerkleTree::new(&test.env, &[(test.claim_addresses[0].clone(), 100)]);
+    assert_eq!(
+        test.contract
+            .try_deposit_merkle(from, token, &100, &tree.root(), &1, &before(12346))
+            .err(),
+        not_allowed
+    );
+    let signer = BytesN::from_array(&test.env, &voucher_signer(1).verifying_key().to_bytes());
+    assert_eq!(
+        test.contract
+            .try_deposit_voucher(from, token, &100, &signer, &before(12346))
+            .err(),
+        not_allowed
+    );
+    let recipient = &test.claim_addresses[0];
+    assert_eq!(
+        test.contract
+            .try_create_stream(from, recipient, token, &100, &12445, &12745)
+            .err(),
+        not_allowed
+    );
+    let schedule = RecurringSchedule {
+        amount_per_period: 100,
+        period: WEEK,
+        start: 12345,
+        count: 1,
+    };
+    assert_eq!(
+        test.contract
+            .try_deposit_recurring(from, recipient, token, &schedule)
+            .err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract
+            .try_create_escrow(from, recipient, &operator, token, &100, &12445)
+            .err(),
+        not_allowed
+    );
+    let terms = vec![
+        &test.env,
+        MilestoneTerms {
+            amount: 100,
+            auto_approve_at: None,
+        },
+    ];
+    assert_eq!(
+        test.contract
+            .try_deposit_milestones(from, recipient, token, &terms)
+            .err(),
+        not_allowed
+    );
+    assert_eq!(test.token.balance(from), 900);
+    assert_eq!(test.token.balance(&test.contract.address), 100);
+}
+
+// Bu test, kayıt kontratının izin vermediği adreslere bakiyelerden (geri alma, miras çekimi,
+// erken talep ve makbuza dönüştürme dahil) token ödenmediğini kontrol eder.
+#[test]
+fn test_registry_gates_balance_payouts() {
+    let test = ClaimableBalanceTest::setup();
+    test.mint(&test.deposit_address, 2000);
+    let expired_id = test.deposit(100, &test.claimants(&[0]), &before(12345));
+    let inheritance_id = setup_inheritance(&test);
+    let (early_id, _) = setup_early_claim(&test);
+    let receipt_id = setup_receipt(&test);
+    test.env.ledger().set_timestamp(12346);
+    test.set_registry();
+    let not_allowed = Some(Ok(Error::NotAllowed.into()));
+    let claimant = &test.claim_addresses[0];
+
+    assert_eq!(test.contract.try_reclaim(&expired_id).err(), not_allowed);
+    assert_eq!(
+        test.contract
+            .try_withdraw_inheritance(&inheritance_id, &100)
+            .err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract.try_early_claim(&early_id, claimant).err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract.try_tokenize(&receipt_id, claimant).err(),
+        not_allowed
+    );
+    assert_eq!(test.token.balance(&test.contract.address), 2300);
+}
+
+// Bu test, kayıt kontratının izin vermediği adreslere Merkle dağıtımı ve kuponlu bakiyeden
+// token ödenmediğini kontrol eder.
+#[test]
+fn test_registry_gates_merkle_and_voucher_payouts() {
+    let test = ClaimableBalanceTest::setup();
+    test.mint(&test.deposit_address, 200);
+    let (distribution_id, tree) = setup_merkle(&test);
+    let voucher_id = setup_voucher(&test, TimeBoundKind::Before);
+    test.set_registry();
+    let not_allowed = Some(Ok(Error::NotAllowed.into()));
+    let claimant = &test.claim_addresses[0];
+
+    assert_eq!(
+        test.contract
+            .try_claim_merkle(
+                &distribution_id,
+                &0,
+                claimant,
+                &100,
+                &tree.proof(&test.env, 0)
+            )
+            .err(),
+        not_allowed
+    );
+    let signature = sign_voucher(&test, &voucher_signer(1), voucher_id, claimant, 100, 0);
+    assert_eq!(
+        test.contract
+            .try_claim_with_voucher(&voucher_id, claimant, &100, &0, &signature)
+            .err(),
+        not_allowed
+    );
+
+    test.env.ledger().set_timestamp(12347);
+    assert_eq!(
+        test.contract.try_reclaim_merkle(&distribution_id).err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract.try_reclaim_voucher_balance(&voucher_id).err(),
+        not_allowed
+    );
+    assert_eq!(test.token.balance(&test.contract.address), 1200);
+}
+
+// Bu test, kayıt kontratının izin vermediği adreslere akış, dönemsel ödeme ve aşamalı
+// bakiyeden token ödenmediğini kontrol eder.
+#[test]
+fn test_registry_gates_schedule_payouts() {
+    let test = ClaimableBalanceTest::setup();
+    test.mint(&test.deposit_address, 1200);
+    let stream_id = setup_stream(&test);
+    let payout_id = setup_recurring(&test);
+    let grant_id = setup_milestones(&test);
+    test.contract.approve_milestone(&grant_id, &0);
+    test.env.ledger().set_timestamp(12595);
+    test.set_registry();
+    let not_allowed = Some(Ok(Error::NotAllowed.into()));
+
+    assert_eq!(test.contract.try_withdraw(&stream_id).err(), not_allowed);
+    assert_eq!(
+        test.contract.try_cancel_stream(&stream_id).err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract.try_claim_recurring(&payout_id).err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract.try_claim_milestones(&grant_id).err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract.try_cancel_milestone(&grant_id, &2).err(),
+        not_allowed
+    );
+    assert_eq!(test.token.balance(&test.contract.address), 1600);
+}
+
+// Bu test, kayıt kontratının izin vermediği alıcı veya satıcıya emanetten token
+// ödenmediğini kontrol eder.
+#[test]
+fn test_registry_gates_escrow_payouts() {
+    let test = ClaimableBalanceTest::setup();
+    test.mint(&test.deposit_address, 200);
+    let escrow_id = setup_escrow(&test);
+    let disputed_id = setup_escrow(&test);
+    test.contract
+        .dispute_escrow(&disputed_id, &test.deposit_address);
+    test.set_registry();
+    let not_allowed = Some(Ok(Error::NotAllowed.into()));
+
+    assert_eq!(
+        test.contract.try_confirm_escrow(&escrow_id).err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract.try_refund_escrow(&escrow_id).err(),
+        not_allowed
+    );
+    assert_eq!(
+        test.contract.try_resolve_escrow(&disputed_id, &300).err(),
+        not_allowed
+    );
+    test.env.ledger().set_timestamp(12445);
+    assert_eq!(
+        test.contract.try_release_escrow(&escrow_id).err(),
+        not_allowed
+    );
+    test.env
+        .ledger()
+        .set_timestamp(12345 + escrow::ARBITRATION_PERIOD);
+    assert_eq!(
+        test.contract.try_expire_dispute(&disputed_id).err(),
+        not_allowed
+    );
+    assert_eq!(test.token.balance(&test.contract.address), 1200);
+}
+
+// Bu test, kayıt kontratını sadece yöneticinin atayabildiğini kontrol eder.
+#[test]
+fn test_set_registry_requires_admin() {
+    let test = ClaimableBalanceTest::setup();
+    let registry = Address::generate(&test.env);
+    test.contract.set_registry(&Some(registry.clone()));
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "set_registry"),
+                    (Some(registry),).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
+
+// Ceza lehtarını döndürür ve erken talep kurallarını %50 en yüksek ceza ve 1000 saniyelik
+// azalma süresiyle ayarlar. İlk talepçiye 13345'te açılan 800 tokenlık bir bakiye oluşturur.
+fn setup_early_claim(test: &ClaimableBalanceTest) -> (u64, Address) {
+    let beneficiary = Address::generate(&test.env);
+    test.contract.set_penalty_config(&Some(PenaltyConfig {
+        beneficiary: beneficiary.clone(),
+        max_penalty_bps: 5000,
+        ramp: 1000,
+    }));
+    let balance_id = test.deposit(800, &test.claimants(&[0]), &after(13345));
+    (balance_id, beneficiary)
+}
+
+// Bu test, cezanın kalan süreyle doğrusal azaldığını ve sınırlarda tam olarak doğru olduğunu kontrol eder.
+#[test]
+fn test_early_claim_penalty_math() {
+    let e = Env::default();
+    let mut config = PenaltyConfig {
+        beneficiary: Address::generate(&e),
+        max_penalty_bps: 10_000,
+        ramp: 1000,
+    };
+    // Azalma süresinden uzun kalan süre en yüksek cezayı verir.
+    assert_eq!(penalty::compute_penalty(&config, 999, u64::MAX), 999);
+    assert_eq!(penalty::compute_penalty(&config, 999, 1000), 999);
+    // Aşağı yuvarlanır: 999 * 999 / 1000 = 998,001 ve 999 * 1 / 1000 = 0,999.
+    assert_eq!(penalty::compute_penalty(&config, 999, 999), 998);
+    assert_eq!(penalty::compute_penalty(&config, 999, 500), 499);
+    assert_eq!(penalty::compute_penalty(&config, 999, 1), 0);
+    assert_eq!(penalty::compute_penalty(&config, 1000, 1), 1);
+    assert_eq!(penalty::compute_penalty(&config, 999, 0), 0);
+
+    // Baz puan önce uygulanır: 999 * %25 = 249,75 -> 249.
+    config.max_penalty_bps = 2500;
+    assert_eq!(penalty::compute_penalty(&config, 999, 1000), 249);
+    assert_eq!(penalty::compute_penalty(&config, 999, 500), 124);
+    config.max_penalty_bps = 0;
+    assert_eq!(penalty::compute_penalty(&config, 999, 1000), 0);
+
+    // Büyük miktar ve azalma süresinde ara sonuçlar taşmaz.
+    config.max_penalty_bps = 10_000;
+    config.ramp = u64::MAX;
+    let amount = i128::MAX / 10_000;
+    assert_eq!(penalty::compute_penalty(&config, amount, u64::MAX), amount);
+    assert_eq!(penalty::compute_penalty(&config, amount, u64::MAX - 1), {
+        // amount * (ramp - 1) / ramp = amount - ⌈amount / ramp⌉
+        amount - (amount + u64::MAX as i128 - 1) / u64::MAX as i128
+    });
+}
+
+// Bu test, erken talepte cezanın lehtara, kalanın talepçiye ödendiğini kontrol eder.
+#[test]
+fn test_early_claim() {
+    let test = ClaimableBalanceTest::setup();
+    let (balance_id, beneficiary) = setup_early_claim(&test);
+
+    // Açılmaya 1000 saniye kaldı: ceza en yüksek değerde.
+    assert_eq!(test.contract.early_claim_penalty(&balance_id), 400);
+    // Açılmaya 500 saniye kaldı: ceza yarıya iner.
+    test.env.ledger().set_timestamp(12845);
+    assert_eq!(test.contract.early_claim_penalty(&balance_id), 200);
+    let paid = test
+        .contract
+        .early_claim(&balance_id, &test.claim_addresses[0]);
+    assert_eq!(paid, 600);
+    assert_eq!(test.token.balance(&test.claim_addresses[0]), 600);
+    assert_eq!(test.token.balance(&beneficiary), 200);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+    assert_eq!(
+        test.contract
+            .try_claim(&balance_id, &test.claim_addresses[0]),
+        Err(Ok(Error::BalanceNotFound.into()))
+    );
+}
+
+// Bu test, açılmaya çok az kala aşağı yuvarlanan cezanın sıfır olabildiğini ve açılma anında
+// cezanın kesilmediğini kontrol eder.
+#[test]
+fn test_early_claim_penalty_boundaries() {
+    let test = ClaimableBalanceTest::setup();
+    let (balance_id, beneficiary) = setup_early_claim(&test);
+
+    // Açılmaya 1000 saniyeden fazla kaldıysa ceza en yüksek değerde kalır.
+    test.env.ledger().set_timestamp(0);
+    assert_eq!(test.contract.early_claim_penalty(&balance_id), 400);
+    // 400 * 3 / 1000 = 1,2 -> 1.
+    test.env.ledger().set_timestamp(13342);
+    assert_eq!(test.contract.early_claim_penalty(&balance_id), 1);
+    // 400 * 2 / 1000 = 0,8 -> 0.
+    test.env.ledger().set_timestamp(13343);
+    assert_eq!(test.contract.early_claim_penalty(&balance_id), 0);
+    test.env.ledger().set_timestamp(13345);
+    assert_eq!(test.contract.early_claim_penalty(&balance_id), 0);
+
+    assert_eq!(
+        test.contract
+            .early_claim(&balance_id, &test.claim_addresses[0]),
+        800
+    );
+    assert_eq!(test.token.balance(&beneficiary), 0);
+}
+
+// Bu test, erken talebin kapalıyken, 'Before' bakiyelerde, miras kilitlerinde ve talepçi
+// olmayanlar için kullanılamadığını kontrol eder.
+#[test]
+fn test_early_claim_not_available() {
+    let test = ClaimableBalanceTest::setup();
+    let (balance_id, _) = setup_early_claim(&test);
+
+    assert!(test
+        .contract
+        .try_early_claim(&balance_id, &test.claim_addresses[1])
+        .is_err());
+
+    let before = test.deposit(100, &test.claimants(&[0]), &before(13345));
+    assert_eq!(
+        test.contract
+            .try_early_claim(&before, &test.claim_addresses[0]),
+        Err(Ok(Error::EarlyClaimNotAvailable.into()))
+    );
+
+    let lock = test.contract.deposit_inheritance(
+        &test.deposit_address,
+        &test.token.address,
+        &100,
+        &test.claimants(&[0]),
+        &1000,
+    );
+    assert_eq!(
+        test.contract
+            .try_early_claim(&lock, &test.claim_addresses[0]),
+        Err(Ok(Error::EarlyClaimNotAvailable.into()))
+    );
+
+    test.contract.set_penalty_config(&None);
+    assert_eq!(
+        test.contract
+            .try_early_claim(&balance_id, &test.claim_addresses[0]),
+        Err(Ok(Error::EarlyClaimNotAvailable.into()))
+    );
+}
+
+// İlk iki talepçiye 13345'te açılan 800 tokenlık bir bakiye oluşturur.
+fn setup_extension(test: &ClaimableBalanceTest) -> u64 {
+    test.deposit(800, &test.claimants(&[0, 1]), &after(13345))
+}
+
+// Bu test, talepçinin kilidi ileri alabildiğini ve bakiyenin yeni zamana kadar talep edilemediğini kontrol eder.
+#[test]
+fn test_extend_lock() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_extension(&test);
+
+    test.contract
+        .extend_lock(&balance_id, &test.claim_addresses[1], &20000);
+    assert_eq!(
+        last_event(&test.env),
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                (symbol_short!("extend"), test.claim_addresses[1].clone()).into_val(&test.env),
+                (balance_id, 13345_u64, 20000_u64).into_val(&test.env),
+            ),
+        ]
+    );
+    // Uzatmayı başlatan talepçinin yanında diğer talepçi de yetkilendirir.
+    let invocation = |address: &Address| {
+        (
+            address.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "extend_lock"),
+                    (balance_id, test.claim_addresses[1].clone(), 20000_u64).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![],
+            },
+        )
+    };
+    assert_eq!(
+        test.env.auths(),
+        std::vec![
+            invocation(&test.claim_addresses[1]),
+            invocation(&test.claim_addresses[0]),
+        ]
+    );
+    assert_eq!(
+        test.contract.get_balance(&balance_id).unwrap().time_bound,
+        after(20000)
+    );
+
+    test.env.ledger().set_timestamp(19999);
+    assert!(test
+        .contract
+        .try_claim(&balance_id, &test.claim_addresses[0])
+        .is_err());
+    test.env.ledger().set_timestamp(20000);
+    test.contract.claim(&balance_id, &test.claim_addresses[0]);
+}
+
+// Bu test, kilidin daha erkene çekilemediğini ve açılmış bir kilidin yeniden kilitlenebildiğini kontrol eder.
+#[test]
+fn test_extend_lock_only_later() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_extension(&test);
+
+    for new_timestamp in [13345, 13344, 0] {
+        assert_eq!(
+            test.contract
+                .try_extend_lock(&balance_id, &test.claim_addresses[0], &new_timestamp),
+            Err(Ok(Error::InvalidSchedule.into()))
+        );
+    }
+
+    // Kilit açıldıktan sonra yeniden kilitleme.
+    test.env.ledger().set_timestamp(14000);
+    test.contract
+        .extend_lock(&balance_id, &test.claim_addresses[0], &13346);
+    test.contract
+        .extend_lock(&balance_id, &test.claim_addresses[0], &15000);
+    assert!(test
+        .contract
+        .try_claim(&balance_id, &test.claim_addresses[0])
+        .is_err());
+}
+
+// Bu test, yatıranın kilidi ancak yatıran ve tüm talepçiler izin verdikten sonra
+// uzatabildiğini ve diğer adreslerin uzatamadığını kontrol eder.
+#[test]
+fn test_extend_lock_policy() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_extension(&test);
+
+    assert_eq!(test.contract.extend_policy(&balance_id), ExtendPolicy::Claimants);
+    for by in [&test.deposit_address, &test.claim_addresses[2]] {
+        assert_eq!(
+            test.contract.try_extend_lock(&balance_id, by, &20000),
+            Err(Ok(Error::ExtensionNotAllowed.into()))
+        );
+    }
+
+    test.contract
+        .set_extend_policy(&balance_id, &ExtendPolicy::DepositorOrClaimants);
+    let invocation = |address: &Address| {
+        (
+            address.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "set_extend_policy"),
+                    (balance_id, ExtendPolicy::DepositorOrClaimants).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![],
+            },
+        )
+    };
+    assert_eq!(
+        test.env.auths(),
+        std::vec![
+            invocation(&test.deposit_address),
+            invocation(&test.claim_addresses[0]),
+            invocation(&test.claim_addresses[1]),
+        ]
+    );
+
+    test.contract
+        .extend_lock(&balance_id, &test.deposit_address, &20000);
+    assert_eq!(
+        test.contract
+            .try_extend_lock(&balance_id, &test.claim_addresses[2], &30000),
+        Err(Ok(Error::ExtensionNotAllowed.into()))
+    );
+}
+
+// Bu test, talepçilerden birinin diğer talepçinin onayı olmadan kilidi uzatamadığını kontrol eder.
+#[test]
+#[should_panic]
+fn test_extend_lock_by_single_co_claimant_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_extension(&test);
+    let by = &test.claim_addresses[1];
+
+    // Yetkiler sadece uzatmayı başlatan talepçi için taklit edilir.
+    test.env.mock_auths(&[MockAuth {
+        address: by,
+        invoke: &MockAuthInvoke {
+            contract: &test.contract.address,
+            fn_name: "extend_lock",
+            args: (balance_id, by.clone(), 20000_u64).into_val(&test.env),
+            sub_invokes: &[],
+        },
+    }]);
+    test.contract.extend_lock(&balance_id, by, &20000);
+}
+
+// Bu test, talepçi listesi boş olan bir bakiyenin uzatma kuralının değiştirilemediğini kontrol eder.
+#[test]
+fn test_set_extend_policy_without_claimants_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let (balance_id, _) = setup_holding(&test);
+
+    assert_eq!(
+        test.contract
+            .try_set_extend_policy(&balance_id, &ExtendPolicy::DepositorOrClaimants),
+        Err(Ok(Error::ExtensionNotAllowed.into()))
+    );
+    assert_eq!(
+        test.contract
+            .try_extend_lock(&balance_id, &test.deposit_address, &20000),
+        Err(Ok(Error::ExtensionNotAllowed.into()))
+    );
+}
+
+// Bu test, 'Before' bakiyelerin ve miras kilitlerinin uzatılamadığını kontrol eder.
+#[test]
+fn test_extend_lock_not_possible() {
+    let test = ClaimableBalanceTest::setup();
+    let before = test.deposit(100, &test.claimants(&[0]), &before(13345));
+    let lock = test.contract.deposit_inheritance(
+        &test.deposit_address,
+        &test.token.address,
+        &100,
+        &test.claimants(&[0]),
+        &1000,
+    );
+    for balance_id in [before, lock] {
+        assert_eq!(
+            test.contract
+                .try_extend_lock(&balance_id, &test.claim_addresses[0], &20000),
+            Err(Ok(Error::ExtensionNotAllowed.into()))
+        );
+    }
+}
+
+// Oy token'ını belirler ve yatıranın kendi adına, `unlock` anında açılan `amount` tokenlık bir
+// kilit oluşturur.
+fn setup_voting_lock(test: &ClaimableBalanceTest, amount: i128, unlock: u64) -> u64 {
+    if test.contract.voting_token().is_none() {
+        test.contract.set_voting_token(&test.token.address);
+    }
+    let own = vec![&test.env, test.deposit_address.clone()];
+    test.deposit(amount, &own, &after(unlock))
+}
+
+// Bu test, oy gücünün kalan kilit süresiyle doğrusal azaldığını ve kilitlerin toplandığını kontrol eder.
+#[test]
+fn test_voting_power_decays() {
+    let test = ClaimableBalanceTest::setup();
+    let half = voting::MAX_LOCK_TIME / 2;
+    setup_voting_lock(&test, 800, 12345 + half);
+
+    assert_eq!(test.contract.voting_power(&test.deposit_address, &12345), 400);
+    assert_eq!(
+        test.contract
+            .voting_power(&test.deposit_address, &(12345 + half / 2)),
+        200
+    );
+    assert_eq!(
+        test.contract
+            .voting_power(&test.deposit_address, &(12345 + half)),
+        0
+    );
+
+    // En uzun süreden uzun kilitler tam güç verir.
+    setup_voting_lock(&test, 100, 12345 + 2 * voting::MAX_LOCK_TIME);
+    assert_eq!(test.contract.voting_power(&test.deposit_address, &12345), 500);
+    // İlk kilit oluşturulmadan önce oy gücü yoktur.
+    assert_eq!(test.contract.voting_power(&test.deposit_address, &12344), 0);
+}
+
+// Bu test, başkası adına, 'Before' türünde veya başka token'la yatırılan bakiyelerin oy gücü
+// vermediğini kontrol eder.
+#[test]
+fn test_voting_power_only_for_own_locks() {
+    let test = ClaimableBalanceTest::setup();
+    test.contract.set_voting_token(&test.token.address);
+    let (other_token, other_admin) = create_token_contract(&test.env, &test.admin);
+    other_admin.mint(&test.deposit_address, &100);
+    let unlock = 12345 + voting::MAX_LOCK_TIME;
+    let own = vec![&test.env, test.deposit_address.clone()];
+
+    test.deposit(100, &test.claimants(&[0]), &after(unlock));
+    test.deposit(100, &own, &before(unlock));
+    test.contract.deposit(
+        &test.deposit_address,
+        &other_token.address,
+        &100,
+        &own,
+        &after(unlock),
+    );
+    test.contract.deposit_inheritance(
+        &test.deposit_address,
+        &test.token.address,
+        &100,
+        &own,
+        &voting::MAX_LOCK_TIME,
+    );
+    for address in [&test.deposit_address, &test.claim_addresses[0]] {
+        assert_eq!(test.contract.voting_power(address, &12345), 0);
+    }
+}
+
+// Bu test, ekleme, uzatma ve talebin oy gücünü güncellediğini ve geçmiş anlardaki oy gücünün
+// değişmediğini kontrol eder.
+#[test]
+fn test_voting_power_checkpoints() {
+    let test = ClaimableBalanceTest::setup();
+    let half = voting::MAX_LOCK_TIME / 2;
+    let balance_id = setup_voting_lock(&test, 400, 12345 + half);
+    assert_eq!(test.contract.voting_power(&test.deposit_address, &12345), 200);
+
+    test.env.ledger().set_timestamp(20000);
+    test.contract
+        .top_up(&balance_id, &test.deposit_address, &400);
+    // 800 * (yarım süre - 7655) / en uzun süre.
+    let after_top_up = 800 * (half - 7655) as i128 / voting::MAX_LOCK_TIME as i128;
+    assert_eq!(
+        test.contract.voting_power(&test.deposit_address, &20000),
+        after_top_up
+    );
+
+    test.env.ledger().set_timestamp(30000);
+    test.contract.extend_lock(
+        &balance_id,
+        &test.deposit_address,
+        &(30000 + voting::MAX_LOCK_TIME),
+    );
+    assert_eq!(test.contract.voting_power(&test.deposit_address, &30000), 800);
+
+    test.env
+        .ledger()
+        .set_timestamp(30000 + voting::MAX_LOCK_TIME);
+    test.contract.claim(&balance_id, &test.deposit_address);
+    assert_eq!(
+        test.contract
+            .voting_power(&test.deposit_address, &(30000 + voting::MAX_LOCK_TIME)),
+        0
+    );
+
+    // Geçmiş kontrol noktaları değişmez.
+    assert_eq!(test.contract.voting_power(&test.deposit_address, &12345), 200);
+    assert_eq!(
+        test.contract.voting_power(&test.deposit_address, &19999),
+        400 * (half - 7654) as i128 / voting::MAX_LOCK_TIME as i128
+    );
+    assert_eq!(
+        test.contract.voting_power(&test.deposit_address, &20000),
+        after_top_up
+    );
+    assert_eq!(test.contract.voting_power(&test.deposit_address, &30000), 800);
+    assert_eq!(
+        test.contract
+            .voting_power(&test.deposit_address, &(30000 + half)),
+        400
+    );
+}
+
+// Bu test, kilitlerin azalmaya başladığı ve açıldığı anlardaki eğim değişikliklerinin, aradaki
+// anlarda işlem olmasa bile geçmiş oy gücüne doğru yansıdığını kontrol eder.
+#[test]
+fn test_voting_power_slope_changes() {
+    let test = ClaimableBalanceTest::setup();
+    let half = voting::MAX_LOCK_TIME / 2;
+    setup_voting_lock(&test, 400, 12345 + half);
+    let balance_id = setup_voting_lock(&test, 100, 12345 + 2 * voting::MAX_LOCK_TIME);
+    assert_eq!(test.contract.voting_power(&test.deposit_address, &12345), 300);
+
+    // İlk kilit açıldıktan ve ikincisi azalmaya başladıktan sonra ikinci kilide ekleme yapılır.
+    let now = 12345 + voting::MAX_LOCK_TIME + half;
+    test.env.ledger().set_timestamp(now);
+    test.contract
+        .top_up(&balance_id, &test.deposit_address, &100);
+    assert_eq!(test.contract.voting_power(&test.deposit_address, &now), 100);
+
+    assert_eq!(
+        test.contract
+            .voting_power(&test.deposit_address, &(12345 + half / 2)),
+        200
+    );
+    assert_eq!(
+        test.contract
+            .voting_power(&test.deposit_address, &(12345 + half)),
+        100
+    );
+    assert_eq!(
+        test.contract
+            .voting_power(&test.deposit_address, &(12345 + voting::MAX_LOCK_TIME + half / 2)),
+        75
+    );
+    assert_eq!(
+        test.contract
+            .voting_power(&test.deposit_address, &(12345 + 2 * voting::MAX_LOCK_TIME)),
+        0
+    );
+}
+
+// Bu test, oy token'ının sadece bir kez belirlenebildiğini kontrol eder.
+#[test]
+fn test_voting_token_set_once() {
+    let test = ClaimableBalanceTest::setup();
+    test.contract.set_voting_token(&test.token.address);
+    assert_eq!(test.contract.voting_token(), Some(test.token.address.clone()));
+    assert!(test
+        .contract
+        .try_set_voting_token(&test.token.address)
+        .is_err());
+}
+
+// Makbuz Wasm'ını yükler ve ilk iki talepçiye 13345'te açılan 800 tokenlık bir bakiye oluşturur.
+// Makbuz standart token arayüzünü uyguladığı için testlerde `token::Client` ile kullanılır.
+fn setup_receipt(test: &ClaimableBalanceTest) -> u64 {
+    let wasm_hash = test
+        .env
+        .deployer()
+        .upload_contract_wasm(wasm_fixtures::wasm("receipt-token"));
+    test.contract.set_receipt_wasm(&wasm_hash);
+    setup_extension(test)
+}
+
+// Bu test, makbuzu transfer etmenin talep hakkını da transfer ettiğini kontrol eder.
+#[test]
+fn test_receipt_transfer_moves_claim_right() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_receipt(&test);
+    let buyer = Address::generate(&test.env);
+
+    let receipt = test
+        .contract
+        .tokenize(&balance_id, &test.claim_addresses[0]);
+    assert_eq!(test.contract.get_receipt(&balance_id), Some(receipt.clone()));
+    let receipt = token::Client::new(&test.env, &receipt);
+    assert_eq!(receipt.balance(&test.claim_addresses[0]), 800);
+    assert_eq!(receipt.decimals(), test.token.decimals());
+    receipt.transfer(&test.claim_addresses[0], &buyer, &800);
+
+    test.env.ledger().set_timestamp(13345);
+    // Talepçiler artık bakiyeyi talep edemez.
+    for claimant in &test.claim_addresses[..2] {
+        assert_eq!(
+            test.contract.try_claim(&balance_id, claimant),
+            Err(Ok(Error::BalanceTokenized.into()))
+        );
+    }
+    // Makbuzu olmayan eski talepçi de makbuzla talep edemez.
+    assert!(test
+        .contract
+        .try_redeem(&balance_id, &test.claim_addresses[0], &1)
+        .is_err());
+
+    assert_eq!(test.contract.redeem(&balance_id, &buyer, &800), 800);
+    assert_eq!(test.token.balance(&buyer), 800);
+    assert_eq!(receipt.balance(&buyer), 0);
+    assert_eq!(test.contract.get_balance(&balance_id), None);
+    assert_eq!(test.contract.get_receipt(&balance_id), None);
+}
+
+// Bu test, makbuzların zaman sınırından önce kullanılamadığını ve parça parça kullanılabildiğini kontrol eder.
+#[test]
+fn test_receipt_partial_redeem() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_receipt(&test);
+    let buyer = Address::generate(&test.env);
+    let receipt = token::Client::new(
+        &test.env,
+        &test.contract.tokenize(&balance_id, &test.claim_addresses[1]),
+    );
+    receipt.transfer(&test.claim_addresses[1], &buyer, &300);
+
+    assert!(test.contract.try_redeem(&balance_id, &buyer, &300).is_err());
+    test.env.ledger().set_timestamp(13345);
+    assert_eq!(
+        test.contract.try_redeem(&balance_id, &buyer, &801),
+        Err(Ok(Error::AmountExceedsBalance.into()))
+    );
+    // Sahip olunandan fazla makbuz yakılamaz.
+    assert!(test.contract.try_redeem(&balance_id, &buyer, &301).is_err());
+
+    test.contract.redeem(&balance_id, &buyer, &300);
+    assert_eq!(test.contract.get_balance(&balance_id).unwrap().amount, 500);
+    test.contract
+        .redeem(&balance_id, &test.claim_addresses[1], &500);
+    assert_eq!(test.token.balance(&buyer), 300);
+    assert_eq!(test.token.balance(&test.claim_addresses[1]), 500);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+}
+
+// Bu test, token'laştırılmış bakiyeye ekleme ve uzatma yapılamadığını, bir bakiyenin iki kez
+// token'laştırılamadığını ve token'laştırılmamış bakiyenin makbuzla talep edilemediğini kontrol eder.
+#[test]
+fn test_tokenized_balance_restrictions() {
+    let test = ClaimableBalanceTest::setup();
+    let balance_id = setup_receipt(&test);
+    let other = test.deposit(100, &test.claimants(&[0]), &after(0));
+    test.contract
+        .tokenize(&balance_id, &test.claim_addresses[0]);
+
+    assert_eq!(
+        test.contract
+            .try_tokenize(&balance_id, &test.claim_addresses[1]),
+        Err(Ok(Error::BalanceTokenized.into()))
+    );
+    assert_eq!(
+        test.contract
+            .try_top_up(&balance_id, &test.deposit_address, &10),
+        Err(Ok(Error::BalanceTokenized.into()))
+    );
+    assert_eq!(
+        test.contract
+            .try_extend_lock(&balance_id, &test.claim_addresses[1], &20000),
+        Err(Ok(Error::BalanceTokenized.into()))
+    );
+    // Talepçi olmayan adres token'laştıramaz.
+    assert!(test
+        .contract
+        .try_tokenize(&other, &test.claim_addresses[2])
+        .is_err());
+
+    test.env.ledger().set_timestamp(13345);
+    assert_eq!(
+        test.contract
+            .try_redeem(&other, &test.claim_addresses[0], &1),
+        Err(Ok(Error::NotTokenizable.into()))
+    );
+}
+
+// `count` adet kilit tanımı oluşturur; `i` sıradaki kilit `10 + i` token'dır ve talepçilere
+// sırayla dağıtılır.
+fn lock_specs(test: &ClaimableBalanceTest, count: u32) -> Vec<LockSpec> {
+    let mut specs = Vec::new(&test.env);
+    for i in 0..count {
+        specs.push_back(LockSpec {
+            amount: 10 + i as i128,
+            claimants: vec![&test.env, test.claim_addresses[i as usize % 3].clone()],
+            time_bound: after(13345 + i as u64),
+        });
+    }
+    specs
+}
+
+// Bu test, toplu yatırmanın tek transferle her kilit için ayrı bir bakiye oluşturduğunu kontrol eder.
+#[test]
+fn test_batch_deposit() {
+    let test = ClaimableBalanceTest::setup();
+    let specs = lock_specs(&test, 3);
+
+    let balance_ids =
+        test.contract
+            .batch_deposit(&test.deposit_address, &test.token.address, &specs);
+    assert_eq!(balance_ids, vec![&test.env, 0_u64, 1, 2]);
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.deposit_address.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "batch_deposit"),
+                    (
+                        test.deposit_address.clone(),
+                        test.token.address.clone(),
+                        specs.clone(),
+                    )
+                        .into_val(&test.env),
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        test.token.address.clone(),
+                        symbol_short!("transfer"),
+                        (
+                            test.deposit_address.clone(),
+                            &test.contract.address,
+                            33_i128,
+                        )
+                            .into_val(&test.env),
+                    )),
+                    sub_invocations: std::vec![]
+                }]
+            }
+        ),]
+    );
+    assert_eq!(test.token.balance(&test.contract.address), 33);
+    for (balance_id, spec) in balance_ids.iter().zip(specs.iter()) {
+        let balance = test.contract.get_balance(&balance_id).unwrap();
+        assert_eq!(balance.amount, spec.amount);
+        assert_eq!(balance.claimants, spec.claimants);
+        assert_eq!(balance.time_bound, spec.time_bound);
+        assert_eq!(balance.depositor, Some(test.deposit_address.clone()));
+    }
+
+    test.env.ledger().set_timestamp(13346);
+    test.contract.claim(&1, &test.claim_addresses[1]);
+    assert_eq!(test.token.balance(&test.claim_addresses[1]), 11);
+    // Üçüncü kilit henüz açılmadı.
+    assert!(test
+        .contract
+        .try_claim(&2, &test.claim_addresses[2])
+        .is_err());
+}
+
+// Bu test, toplu yatırmada her kilidin `deposit` gibi kendi miktarının ücretini ödediğini ve
+// küçük kilitlerin net miktarının sıfırın altına inmediğini kontrol eder.
+#[test]
+fn test_batch_deposit_fee() {
+    let test = ClaimableBalanceTest::setup();
+    let treasury = test.set_fees(1000, 0);
+    let specs = lock_specs(&test, 5);
+
+    let balance_ids =
+        test.contract
+            .batch_deposit(&test.deposit_address, &test.token.address, &specs);
+    // Her kilit 1 ücret öder.
+    assert_eq!(test.token.balance(&treasury), 5);
+    assert_eq!(test.token.balance(&test.contract.address), 55);
+    let amounts: std::vec::Vec<i128> = balance_ids
+        .iter()
+        .map(|balance_id| test.contract.get_balance(&balance_id).unwrap().amount)
+        .collect();
+    assert_eq!(amounts, [9, 10, 11, 12, 13]);
+
+    // 1 tokenlık kilitlerin ücreti sıfıra yuvarlanır; toplamın ücreti hiçbir kilitten kesilmez.
+    let mut specs = Vec::new(&test.env);
+    for _ in 0..batch::MAX_BATCH_SIZE {
+        specs.push_back(LockSpec {
+            amount: 1,
+            claimants: test.claimants(&[0]),
+            time_bound: after(0),
+        });
+    }
+    let balance_ids =
+        test.contract
+            .batch_deposit(&test.deposit_address, &test.token.address, &specs);
+    assert_eq!(test.token.balance(&treasury), 5);
+    for balance_id in balance_ids.iter() {
+        assert_eq!(test.contract.get_balance(&balance_id).unwrap().amount, 1);
+    }
+}
+
+// Bu test, kilitlerden biri geçersizse, boş veya çok büyük bir listede ve transferde eksik token
+// ulaştığında toplu yatırmanın hiçbir bakiye oluşturmadan reddedildiğini kontrol eder.
+#[test]
+fn test_batch_deposit_is_atomic() {
+    let test = ClaimableBalanceTest::setup();
+    let mut specs = lock_specs(&test, 3);
+    let mut invalid = specs.get(2).unwrap();
+    invalid.claimants = Vec::new(&test.env);
+    specs.set(2, invalid);
+
+    assert_eq!(
+        test.contract
+            .try_batch_deposit(&test.deposit_address, &test.token.address, &specs),
+        Err(Ok(Error::NoClaimants.into()))
+    );
+    assert_eq!(
+        test.contract.try_batch_deposit(
+            &test.deposit_address,
+            &test.token.address,
+            &Vec::new(&test.env)
+        ),
+        Err(Ok(Error::InvalidBatch.into()))
+    );
+    assert_eq!(
+        test.contract.try_batch_deposit(
+            &test.deposit_address,
+            &test.token.address,
+            &lock_specs(&test, batch::MAX_BATCH_SIZE + 1)
+        ),
+        Err(Ok(Error::InvalidBatch.into()))
+    );
+    // Transferde ücret kesen token'da toplam kilitlere paylaştırılamaz.
+    let fee_token = test.fee_token(1000);
+    assert_eq!(
+        test.contract.try_batch_deposit(
+            &test.deposit_address,
+            &fee_token.address,
+            &lock_specs(&test, 3)
+        ),
+        Err(Ok(Error::InvalidBatch.into()))
+    );
+
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+    assert_eq!(fee_token.balance(&test.deposit_address), 1000);
+    assert_eq!(test.contract.get_balance(&0), None);
+    // Hiçbir kimlik harcanmadı.
+    assert_eq!(
+        test.deposit(10, &test.claimants(&[0]), &after(0)),
+        0
+    );
+}
+
+// Bu test, oy kilitlerinin toplu yatırılamadığını, oy token'ıyla başkası için kilitlerin ise
+// yatırılabildiğini kontrol eder.
+#[test]
+fn test_batch_deposit_rejects_voting_locks() {
+    let test = ClaimableBalanceTest::setup();
+    test.contract.set_voting_token(&test.token.address);
+    let mut specs = lock_specs(&test, 3);
+    let mut own = specs.get(1).unwrap();
+    own.claimants = vec![&test.env, test.deposit_address.clone()];
+    specs.set(1, own);
+
+    assert_eq!(
+        test.contract
+            .try_batch_deposit(&test.deposit_address, &test.token.address, &specs),
+        Err(Ok(Error::InvalidBatch.into()))
+    );
+    assert_eq!(test.token.balance(&test.deposit_address), 1000);
+
+    let balance_ids = test.contract.batch_deposit(
+        &test.deposit_address,
+        &test.token.address,
+        &lock_specs(&test, 3),
+    );
+    assert_eq!(balance_ids.len(), 3);
+    assert_eq!(
+        test.contract
+            .voting_power(&test.deposit_address, &test.env.ledger().timestamp()),
+        0
+    );
+}
+
+// Bu test, en büyük toplu yatırmanın en kötü durumda da işlem kaynak sınırlarının altında
+// kaldığını ölçer.
+// Sınırlar ana ağın işlem başına sınırlarıdır (100M talimat, 40 MB bellek, 25 yazma kaydı,
+// 8198 bayt olay). Kontrat Wasm olarak değil yerel olarak çalıştığı için talimat ve bellek
+// ölçümleri gerçekte olandan düşüktür; bu yüzden bunlar için geniş bir pay bırakılır.
+#[test]
+fn test_batch_deposit_budget() {
+    let test = ClaimableBalanceTest::setup();
+    let specs = lock_specs(&test, batch::MAX_BATCH_SIZE);
+    // En kötü durum: her kilit ücret öder ve oy token'ıyla yatırılır (oy kilidi olmadan).
+    test.set_fees(1000, 0);
+    test.contract.set_voting_token(&test.token.address);
+
+    test.env.cost_estimate().budget().reset_default();
+    let balance_ids =
+        test.contract
+            .batch_deposit(&test.deposit_address, &test.token.address, &specs);
+    assert_eq!(balance_ids.len(), batch::MAX_BATCH_SIZE);
+
+    let resources = test.env.cost_estimate().resources();
+    // Her kilit bir bakiye kaydı yazar; ayrıca kontrat örneği, kontratın, yatıranın ve hazinenin
+    // token bakiyeleri ve yatıranın yetkilendirme nonce'u yazılır.
+    assert_eq!(resources.write_entries, batch::MAX_BATCH_SIZE + 5);
+    assert!(resources.write_entries <= 25);
+    assert!(resources.contract_events_size_bytes <= 8198);
+    assert!(resources.instructions <= 100_000_000 / 4);
+    assert!(resources.mem_bytes <= 40 * 1024 * 1024 / 4);
+
+    // Kilit başına maliyet, tek tek yatırmadan düşüktür.
+    let batch_instructions = resources.instructions;
+    test.deposit(10, &test.claimants(&[0]), &after(0));
+    let single_instructions = test.env.cost_estimate().resources().instructions;
+    assert!(batch_instructions < single_instructions * batch::MAX_BATCH_SIZE as i64);
+}
@@ -0,0 +1,105 @@
+// Kademeli son tarihlerle yedek talepçi kademeleri (tier).
+//
+// Birinci kademe ilk son tarihe kadar talep edebilir; talep edilmezse ikinci kademe bir
+// sonraki son tarihe kadar talep edebilir, ve böyle devam eder. Son kademenin süresi dolunca
+// bakiye `reclaim` ile yatırana döner.
+//
+// Kademeli bakiye, tüm kademelerin talepçilerini içeren ve son kademenin son tarihine kadar
+// açık ('Before') sıradan bir bakiyedir. Kademeler bakiyenin yanında ayrı bir anahtarda
+// tutulur ve `claim` talepçinin kademesinin o anda açık olup olmadığını ayrıca kontrol eder.
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env, Vec};
+
+use crate::{
+    check_deposit, create_balance, receive_tokens, registry, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, Error, TimeBound,
+    TimeBoundKind, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ClaimantTier {
+    // Bu kademedeki talepçiler.
+    pub claimants: Vec<Address>,
+    // Bu kademenin talep edebileceği son an (dahil). Kademe, önceki kademenin son
+    // tarihinden sonra açılır.
+    pub deadline: u64,
+}
+
+// Bakiye kademeliyse, `claimant`'ın kademesinin şu anda açık olduğunu kontrol eder;
+// değilse `Error::TierNotActive` ile paniklet. Kademesiz bakiyelerde bir şey yapmaz.
+pub(crate) fn check_tier(env: &Env, balance_id: u64, claimant: &Address) {
+    let Some(tiers) = env
+        .storage()
+        .persistent()
+        .get::<_, Vec<ClaimantTier>>(&DataKey::Tiers(balance_id))
+    else {
+        return;
+    };
+    env.storage().persistent().extend_ttl(
+        &DataKey::Tiers(balance_id),
+        BALANCE_LIFETIME_THRESHOLD,
+        BALANCE_BUMP_AMOUNT,
+    );
+    let now = env.ledger().timestamp();
+    let mut opens_after = None;
+    for tier in tiers.iter() {
+        if tier.claimants.contains(claimant) {
+            if opens_after.is_some_and(|previous| now <= previous) || now > tier.deadline {
+                panic_with_error!(env, Error::TierNotActive);
+            }
+            return;
+        }
+        opens_after = Some(tier.deadline);
+    }
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `from`'dan `amount` kadar token yatırarak kademeli bir bakiye oluşturur ve kimliğini döndürür.
+    // Kademeler öncelik sırasıyla verilir ve son tarihleri kesin olarak artmalıdır.
+    // Talepçi sınırı ve tekrar eden adres kontrolü tüm kademelerin toplamına uygulanır.
+    pub fn deposit_tiered(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        tiers: Vec<ClaimantTier>,
+    ) -> u64 {
+        let mut claimants = Vec::new(&env);
+        let mut last_deadline = None;
+        for tier in tiers.iter() {
+            if tier.claimants.is_empty() {
+                panic_with_error!(&env, Error::NoClaimants);
+            }
+            if last_deadline.is_some_and(|previous| tier.deadline <= previous) {
+                panic_with_error!(&env, Error::InvalidSchedule);
+            }
+            claimants.append(&tier.claimants);
+            last_deadline = Some(tier.deadline);
+        }
+        check_deposit(&env, amount, &claimants);
+        from.require_auth();
+        registry::ensure_allowed(&env, &from);
+
+        let amount = receive_tokens(&env, &token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &amount)
+        });
+        let time_bound = TimeBound {
+            kind: TimeBoundKind::Before,
+            // `check_deposit` boş listeyi reddettiği için en az bir kademe vardır.
+            timestamp: last_deadline.unwrap(),
+        };
+        let balance_id = create_balance(&env, from, token, amount, claimants, time_bound);
+        let key = DataKey::Tiers(balance_id);
+        env.storage().persistent().set(&key, &tiers);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        balance_id
+    }
+
+    // Bakiyenin talepçi kademelerini döndürür; bakiye kademeli değilse `None` döner.
+    pub fn get_tiers(env: Env, balance_id: u64) -> Option<Vec<ClaimantTier>> {
+        env.storage().persistent().get(&DataKey::Tiers(balance_id))
+    }
+}
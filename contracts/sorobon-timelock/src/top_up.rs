@@ -0,0 +1,63 @@
+// Mevcut bir bakiyeye sonradan token eklenmesi (top-up).
+//
+// Bakiye henüz talep edilmemiş veya geri alınmamışsa, aynı token'dan daha fazlası
+// bakiyeye eklenebilir. Kimin ekleyebileceği her bakiye için ayrı ayarlanır.
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Env};
+
+use crate::{
+    events, fee, load_balance, pause, receipt, receive_tokens, registry, write_balance,
+    ClaimableBalanceContract, ClaimableBalanceContractArgs, ClaimableBalanceContractClient, Error,
+};
+
+// Bir bakiyeye kimlerin token ekleyebileceği.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TopUpPolicy {
+    // Sadece bakiyeyi ilk yatıran adres ekleyebilir (varsayılan).
+    DepositorOnly,
+    // Herhangi bir adres ekleyebilir.
+    Anyone,
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `from`'dan `amount` kadar token'ı `balance_id` kimlikli bakiyeye ekler.
+    // Token bakiyenin token'ı ile aynıdır; yatırma ücreti kesilir ve kontrata gerçekten
+    // ulaşan miktar eklenir. `from` yetkilendirmelidir.
+    pub fn top_up(env: Env, balance_id: u64, from: Address, amount: i128) {
+        pause::ensure_not_paused(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        let mut balance = load_balance(&env, balance_id);
+        // Makbuzlar bakiyeyle bire bir olduğu için token'laştırılmış bakiyeye ekleme yapılamaz.
+        receipt::ensure_not_tokenized(&env, balance_id);
+        if balance.top_up_policy == TopUpPolicy::DepositorOnly
+            && balance.depositor.as_ref() != Some(&from)
+        {
+            panic_with_error!(&env, Error::TopUpNotAllowed);
+        }
+        from.require_auth();
+        registry::ensure_allowed(&env, &from);
+
+        let received = receive_tokens(&env, &balance.token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &amount)
+        });
+        let (amount, fee) = fee::charge_deposit_fee(&env, &balance.token, received);
+        balance.amount += amount;
+        write_balance(&env, balance_id, &balance);
+        events::top_up(&env, &from, balance_id, &balance.token, amount, fee);
+    }
+
+    // Bakiyeye kimlerin token ekleyebileceğini değiştirir. Sadece bakiyeyi yatıran çağırabilir.
+    pub fn set_top_up_policy(env: Env, balance_id: u64, policy: TopUpPolicy) {
+        let mut balance = load_balance(&env, balance_id);
+        balance
+            .depositor
+            .as_ref()
+            .unwrap_or_else(|| panic!("depositor of this balance is unknown")) // "bakiyeyi yatıran bilinmiyor"
+            .require_auth();
+        balance.top_up_policy = policy;
+        write_balance(&env, balance_id, &balance);
+    }
+}
@@ -0,0 +1,151 @@
+// Kontratın yükseltilmesi (upgrade) ve depolama şemasının sürümlenmesi/göçü (migrate).
+//
+// Zaman kilitli fonlar kullanıcılar tarafından elle taşınamayacağı için, hata düzeltmeleri
+// kontratın Wasm kodu yerinde değiştirilerek yayınlanır. Kod değiştikten sonra eski düzende
+// yazılmış kayıtlar `migrate` ile güncel şemaya dönüştürülür.
+use soroban_sdk::{contractimpl, contracttype, Address, BytesN, Env, Vec};
+
+use crate::{
+    admin, write_balance, ClaimableBalance, ClaimableBalanceContract,
+    ClaimableBalanceContractArgs, ClaimableBalanceContractClient, DataKey, TimeBound, TopUpPolicy,
+};
+
+// Bu kodun beklediği depolama şeması sürümü.
+// - 1: Tek bakiye, örnek (instance) depolamada `LegacyDataKey::Balance` altında tutulur.
+//      Sürüm bilgisi saklamayan eski kontratlar bu sürümde kabul edilir. Bu sürümün `upgrade`
+//      fonksiyonu ve yöneticisi olmadığı için kodu yerinde değiştirilemez; bakiyesi eski
+//      kontrattan talep edilip yeni bir kontrata yatırılmalıdır. Bu yüzden göçü desteklenmez.
+// - 2: Tek bakiye, kalıcı (persistent) depolamada `LegacyDataKey::Balance` altında tutulur.
+// - 3: Bakiye kaydına yatıran adres (`depositor`) eklendi.
+// - 4: Bir kontrat birden fazla bakiye tutar; her bakiye `DataKey::Balance(id)` altındadır
+//      ve kayda `top_up_policy` eklendi. Tek kullanımlık `LegacyDataKey::Init` kaldırıldı.
+pub const SCHEMA_VERSION: u32 = 4;
+
+// Şema sürümü 4'ten önce kullanılan depolama anahtarları.
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub(crate) enum LegacyDataKey {
+    Init,
+    Balance,
+}
+
+// Şema sürümü 2'de (ve 1'de) kullanılan bakiye kaydı düzeni.
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub(crate) struct ClaimableBalanceV1 {
+    pub token: Address,
+    pub amount: i128,
+    pub claimants: Vec<Address>,
+    pub time_bound: TimeBound,
+}
+
+// Şema sürümü 3'te kullanılan bakiye kaydı düzeni.
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub(crate) struct ClaimableBalanceV3 {
+    pub token: Address,
+    pub amount: i128,
+    pub claimants: Vec<Address>,
+    pub time_bound: TimeBound,
+    pub depositor: Option<Address>,
+}
+
+// Depolamada kayıtlı şema sürümünü okur. Kayıt yoksa sürüm 1 kabul edilir.
+pub(crate) fn read_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Version)
+        .unwrap_or(1)
+}
+
+// Şema sürümünü örnek (instance) depolamaya yazar.
+pub(crate) fn write_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::Version, &version);
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // Kontratın Wasm kodunu, daha önce ağa yüklenmiş `new_wasm_hash` ile değiştirir.
+    // Sadece yönetici çağırabilir. Depolama olduğu gibi korunur; yeni kod eski düzende
+    // veri bekliyorsa yönetici ardından `migrate` çağırmalıdır.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        admin::require_admin(&env);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    // Depolamayı kayıtlı şema sürümünden `SCHEMA_VERSION`'a adım adım dönüştürür.
+    // Sadece yönetici çağırabilir. Depolama zaten güncelse hiçbir şey yapmaz. Yükseltilemeyen
+    // sürüm 1 kontratlarının depolaması desteklenmez.
+    pub fn migrate(env: Env) {
+        admin::require_admin(&env);
+
+        let mut version = read_version(&env);
+        // Depolama bu koddan daha yeni bir sürümle yazılmışsa geri dönüştürülemez.
+        if version > SCHEMA_VERSION {
+            panic!("storage schema is newer than contract code"); // "depolama şeması koddan daha yeni"
+        }
+        // Her adım, depolamayı bir sonraki sürüme taşır.
+        while version < SCHEMA_VERSION {
+            match version {
+                2 => migrate_v2_to_v3(&env),
+                3 => migrate_v3_to_v4(&env),
+                _ => panic!("unsupported schema version"), // "desteklenmeyen şema sürümü"
+            }
+            version += 1;
+        }
+        write_version(&env, version);
+    }
+
+    // Depolamanın şu anda hangi şema sürümünde olduğunu döndürür.
+    pub fn version(env: Env) -> u32 {
+        read_version(&env)
+    }
+}
+
+// Sürüm 2 -> 3: Bakiye kaydına `depositor` alanını ekler. Eski kayıtlarda yatıran adres
+// saklanmadığı için `None` yazılır; bu bakiyeler talepçiler tarafından talep edilebilir
+// kalır ancak süreleri dolduğunda geri alınamaz.
+fn migrate_v2_to_v3(env: &Env) {
+    let key = LegacyDataKey::Balance;
+    let legacy: Option<ClaimableBalanceV1> = env.storage().persistent().get(&key);
+    if let Some(balance) = legacy {
+        env.storage().persistent().set(
+            &key,
+            &ClaimableBalanceV3 {
+                token: balance.token,
+                amount: balance.amount,
+                claimants: balance.claimants,
+                time_bound: balance.time_bound,
+                depositor: None,
+            },
+        );
+    }
+}
+
+// Sürüm 3 -> 4: Tek bakiyeyi 0 kimliğiyle `DataKey::Balance(0)` altına taşır ve tek
+// kullanımlık `Init` işaretini kaldırır; kontrat artık yeni yatırımlar kabul eder.
+// Taşınan bakiyeye sadece yatıranın token ekleyebileceği varsayılan kural uygulanır.
+fn migrate_v3_to_v4(env: &Env) {
+    let key = LegacyDataKey::Balance;
+    let legacy: Option<ClaimableBalanceV3> = env.storage().persistent().get(&key);
+    if let Some(balance) = legacy {
+        write_balance(
+            env,
+            0,
+            &ClaimableBalance {
+                token: balance.token,
+                amount: balance.amount,
+                claimants: balance.claimants,
+                time_bound: balance.time_bound,
+                depositor: balance.depositor,
+                top_up_policy: TopUpPolicy::DepositorOnly,
+            },
+        );
+        env.storage().persistent().remove(&key);
+    }
+    // Tek bakiye daha önce talep edilmiş olsa bile 0 kimliği kullanılmış sayılır.
+    if env.storage().instance().has(&LegacyDataKey::Init) {
+        env.storage().instance().set(&DataKey::NextId, &1_u64);
+        env.storage().instance().remove(&LegacyDataKey::Init);
+    }
+}
@@ -0,0 +1,62 @@
+// Yatırma (deposit) girdilerinin doğrulanması ve ilgili yapılandırma.
+use soroban_sdk::{contractimpl, panic_with_error, Address, Env, Vec};
+
+use crate::{
+    admin, ClaimableBalanceContract, ClaimableBalanceContractArgs,
+    ClaimableBalanceContractClient, DataKey, Error,
+};
+
+// Yönetici ayarlamadıysa bir bakiyede izin verilen en yüksek talepçi sayısı.
+pub const DEFAULT_MAX_CLAIMANTS: u32 = 10;
+// Yöneticinin ayarlayabileceği üst sınır. Talep sırasında liste tek tek tarandığından,
+// çok uzun listeler işlem kaynak sınırlarını aşabilir.
+pub const MAX_CLAIMANTS_LIMIT: u32 = 100;
+
+// Bir bakiyede izin verilen en yüksek talepçi sayısını okur.
+pub(crate) fn read_max_claimants(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxClaimants)
+        .unwrap_or(DEFAULT_MAX_CLAIMANTS)
+}
+
+// Yatırılan miktarın ve talepçi listesinin geçerli olduğunu kontrol eder;
+// değilse ilgili tipli hatayla paniklet.
+pub(crate) fn validate_deposit(env: &Env, amount: i128, claimants: &Vec<Address>) {
+    if amount <= 0 {
+        panic_with_error!(env, Error::InvalidAmount);
+    }
+    if claimants.is_empty() {
+        panic_with_error!(env, Error::NoClaimants);
+    }
+    // Talepçi sayısı sınırı aşıyorsa, kontratın aşırı büyük bir listeyle şişirilmesini önle.
+    if claimants.len() > read_max_claimants(env) {
+        panic_with_error!(env, Error::TooManyClaimants);
+    }
+    // Her adresin listede ilk geçtiği yer kendi konumu olmalıdır.
+    for (i, claimant) in claimants.iter().enumerate() {
+        if claimants.first_index_of(&claimant) != Some(i as u32) {
+            panic_with_error!(env, Error::DuplicateClaimant);
+        }
+    }
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // Bir bakiyede izin verilen en yüksek talepçi sayısını değiştirir. Sadece yönetici çağırabilir.
+    // Değer 1 ile `MAX_CLAIMANTS_LIMIT` arasında olmalıdır.
+    pub fn set_max_claimants(env: Env, max_claimants: u32) {
+        admin::require_admin(&env);
+        if max_claimants == 0 || max_claimants > MAX_CLAIMANTS_LIMIT {
+            panic_with_error!(&env, Error::InvalidMaxClaimants);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxClaimants, &max_claimants);
+    }
+
+    // Bir bakiyede izin verilen en yüksek talepçi sayısını döndürür.
+    pub fn max_claimants(env: Env) -> u32 {
+        read_max_claimants(&env)
+    }
+}
@@ -0,0 +1,324 @@
+// Kilitli bakiyelerden türetilen oy gücü (vote-escrow).
+//
+// Yönetici bir oy token'ı belirlediyse, bu token'la yatırılan ve tek talepçisi yatıranın kendisi
+// olan 'After' bakiyeleri (kendi token'ını kilitleme) sahibine oy gücü verir. Bir kilidin oy
+// gücü `miktar * min(kalan_süre, MAX_LOCK_TIME) / MAX_LOCK_TIME` olur; kilit açılana kadar
+// doğrusal olarak sıfıra iner.
+//
+// Her adres için kilitlerinin toplamı, oy gücü ve azalma hızı (eğim) olarak kontrol noktalarında
+// (checkpoint) saklanır; kontrol noktasının boyutu kilit sayısından bağımsızdır. Bir kilidin
+// azalmaya başladığı ve açıldığı anlarda eğim değişir; bu değişiklikler anlarına göre ayrı
+// anahtarlarda tutulur ve zamanı geldiğinde kontrol noktasına işlenir. Geçmişteki bir andaki oy
+// gücü, o andan önceki son kontrol noktası ikili aramayla bulunarak hesaplanır.
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{
+    admin, read_balance, ClaimableBalance, ClaimableBalanceContract, ClaimableBalanceContractArgs,
+    ClaimableBalanceContractClient, DataKey, TimeBound, TimeBoundKind, BALANCE_BUMP_AMOUNT,
+    BALANCE_LIFETIME_THRESHOLD,
+};
+
+// Tam oy gücü veren en uzun kilit süresi (4 yıl). Daha uzun kilitler bundan fazla güç vermez.
+pub const MAX_LOCK_TIME: u64 = 4 * 365 * 24 * 60 * 60;
+
+// Oy gücü veren açık bir kilit. Kilit değiştiğinde eski katkısını çıkarabilmek için bakiye
+// başına saklanır.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VotingLock {
+    pub amount: i128,
+    // Kilidin açılma zamanı; bu andan sonra kilit oy gücü vermez.
+    pub unlock: u64,
+}
+
+// Bir adresin `timestamp` anındaki toplam oy gücü ve saniyedeki azalması. Bölme sadece okurken
+// yapılsın diye iki değer de `MAX_LOCK_TIME` ile çarpılmış olarak tutulur; böylece kilitler
+// toplanırken yuvarlama hatası birikmez.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VotingCheckpoint {
+    pub timestamp: u64,
+    pub bias: i128,
+    pub slope: i128,
+}
+
+fn read_voting_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::VotingToken)
+}
+
+fn checkpoint_count(env: &Env, owner: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VotingCheckpoints(owner.clone()))
+        .unwrap_or(0)
+}
+
+fn read_checkpoint(env: &Env, owner: &Address, index: u32) -> VotingCheckpoint {
+    let key = DataKey::VotingCheckpoint(owner.clone(), index);
+    let checkpoint = env.storage().persistent().get(&key).unwrap();
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    checkpoint
+}
+
+// Kontrol noktasını listenin sonuna ekler. Son kontrol noktasıyla aynı andaysa onun yerine yazar;
+// aynı anda yapılan değişiklikler tek bir kontrol noktasında birleşir.
+fn write_checkpoint(env: &Env, owner: &Address, checkpoint: &VotingCheckpoint) {
+    let count = checkpoint_count(env, owner);
+    let index =
+        if count > 0 && read_checkpoint(env, owner, count - 1).timestamp == checkpoint.timestamp {
+            count - 1
+        } else {
+            count
+        };
+    let key = DataKey::VotingCheckpoint(owner.clone(), index);
+    env.storage().persistent().set(&key, checkpoint);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    let key = DataKey::VotingCheckpoints(owner.clone());
+    env.storage().persistent().set(&key, &(index + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// `timestamp` anında geçerli olan (o andan önceki son) kontrol noktasının sırasını bulur.
+fn checkpoint_at(env: &Env, owner: &Address, timestamp: u64) -> Option<u32> {
+    let (mut low, mut high) = (0, checkpoint_count(env, owner));
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if read_checkpoint(env, owner, middle).timestamp <= timestamp {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    low.checked_sub(1)
+}
+
+// `owner`'ın henüz işlenmemiş eğim değişikliklerinin anları, artan sırada.
+fn read_slope_times(env: &Env, owner: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VotingSlopeTimes(owner.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn write_slope_times(env: &Env, owner: &Address, times: &Vec<u64>) {
+    let key = DataKey::VotingSlopeTimes(owner.clone());
+    env.storage().persistent().set(&key, times);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+fn read_slope_change(env: &Env, owner: &Address, timestamp: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VotingSlopeChange(owner.clone(), timestamp))
+        .unwrap_or(0)
+}
+
+// `timestamp` anındaki eğim değişikliğine `delta` ekler. Değişiklik sıfırlanırsa kaydı siler.
+fn add_slope_change(env: &Env, owner: &Address, times: &mut Vec<u64>, timestamp: u64, delta: i128) {
+    let key = DataKey::VotingSlopeChange(owner.clone(), timestamp);
+    let change = read_slope_change(env, owner, timestamp) + delta;
+    match times.binary_search(timestamp) {
+        Ok(index) if change == 0 => {
+            times.remove(index);
+        }
+        Err(index) => times.insert(index, timestamp),
+        Ok(_) => {}
+    }
+    if change == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &change);
+        env.storage().persistent().extend_ttl(
+            &key,
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        );
+    }
+}
+
+// Kontrol noktasını, eğim değişmeden `timestamp` anına ilerletir.
+fn decay(checkpoint: &mut VotingCheckpoint, timestamp: u64) {
+    checkpoint.bias -= checkpoint.slope * (timestamp - checkpoint.timestamp) as i128;
+    checkpoint.timestamp = timestamp;
+}
+
+// `lock`'un kontrol noktası anındaki katkısını ekler (`sign` = 1) veya çıkarır (`sign` = -1).
+// En uzun süreden uzun bir kilit, kalan süresi `MAX_LOCK_TIME`'a inene kadar azalmaz.
+fn apply_lock(
+    env: &Env,
+    owner: &Address,
+    checkpoint: &mut VotingCheckpoint,
+    times: &mut Vec<u64>,
+    lock: &VotingLock,
+    sign: i128,
+) {
+    if lock.unlock <= checkpoint.timestamp {
+        return;
+    }
+    let remaining = lock.unlock - checkpoint.timestamp;
+    let amount = sign * lock.amount;
+    checkpoint.bias += amount * remaining.min(MAX_LOCK_TIME) as i128;
+    if remaining <= MAX_LOCK_TIME {
+        checkpoint.slope += amount;
+    } else {
+        add_slope_change(env, owner, times, lock.unlock - MAX_LOCK_TIME, amount);
+    }
+    add_slope_change(env, owner, times, lock.unlock, -amount);
+}
+
+// Tek talepçisi `depositor` olan bir 'After' bakiyesi (kendi token'ını kilitleme) olup olmadığını
+// döndürür.
+fn is_self_lock(depositor: &Address, claimants: &Vec<Address>, time_bound: &TimeBound) -> bool {
+    time_bound.kind == TimeBoundKind::After
+        && claimants.len() == 1
+        && claimants.get_unchecked(0) == *depositor
+}
+
+// Bakiyenin oy gücü verip vermediğini döndürür: oy token'ıyla yatırılmış, miras kilidi olmayan ve
+// token'laştırılmamış bir kendi kilidi.
+fn gives_power(env: &Env, balance_id: u64, balance: &ClaimableBalance, token: &Address) -> bool {
+    let storage = env.storage().persistent();
+    balance.token == *token
+        && balance.depositor.as_ref().is_some_and(|depositor| {
+            is_self_lock(depositor, &balance.claimants, &balance.time_bound)
+        })
+        && !storage.has(&DataKey::InactivityWindow(balance_id))
+        && !storage.has(&DataKey::Receipt(balance_id))
+}
+
+// `from`'un `token` ile yatıracağı bakiyenin oy kilidi olup olmayacağını döndürür.
+pub(crate) fn is_voting_lock(
+    env: &Env,
+    from: &Address,
+    token: &Address,
+    claimants: &Vec<Address>,
+    time_bound: &TimeBound,
+) -> bool {
+    read_voting_token(env).is_some_and(|voting_token| voting_token == *token)
+        && is_self_lock(from, claimants, time_bound)
+}
+
+// `owner`'ın `balance_id` kilidini `lock` ile değiştirir (`None` ise çıkarır). Önce zamanı gelmiş
+// eğim değişikliklerini her biri kendi anında bir kontrol noktası olacak şekilde işler, sonra
+// eski kilidin katkısını çıkarıp yenisini ekler ve sonucu yeni bir kontrol noktası olarak yazar.
+fn update_lock(env: &Env, owner: &Address, balance_id: u64, lock: Option<VotingLock>) {
+    let lock_key = DataKey::VotingLock(balance_id);
+    let old: Option<VotingLock> = env.storage().persistent().get(&lock_key);
+    if old.is_none() && lock.is_none() {
+        return;
+    }
+
+    let now = env.ledger().timestamp();
+    let count = checkpoint_count(env, owner);
+    let mut checkpoint = match count {
+        0 => VotingCheckpoint {
+            timestamp: now,
+            bias: 0,
+            slope: 0,
+        },
+        _ => read_checkpoint(env, owner, count - 1),
+    };
+    let mut times = read_slope_times(env, owner);
+    while let Some(timestamp) = times.first().filter(|timestamp| *timestamp <= now) {
+        decay(&mut checkpoint, timestamp);
+        checkpoint.slope += read_slope_change(env, owner, timestamp);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::VotingSlopeChange(owner.clone(), timestamp));
+        times.pop_front();
+        write_checkpoint(env, owner, &checkpoint);
+    }
+    decay(&mut checkpoint, now);
+
+    if let Some(old) = old {
+        apply_lock(env, owner, &mut checkpoint, &mut times, &old, -1);
+    }
+    match lock {
+        Some(lock) => {
+            apply_lock(env, owner, &mut checkpoint, &mut times, &lock, 1);
+            env.storage().persistent().set(&lock_key, &lock);
+            env.storage().persistent().extend_ttl(
+                &lock_key,
+                BALANCE_LIFETIME_THRESHOLD,
+                BALANCE_BUMP_AMOUNT,
+            );
+        }
+        None => env.storage().persistent().remove(&lock_key),
+    }
+    write_checkpoint(env, owner, &checkpoint);
+    write_slope_times(env, owner, &times);
+}
+
+// Bakiye yazıldıktan sonra sahibinin oy gücü kayıtlarını günceller.
+pub(crate) fn sync_balance(env: &Env, balance_id: u64, balance: &ClaimableBalance) {
+    let Some(token) = read_voting_token(env) else {
+        return;
+    };
+    let Some(depositor) = balance.depositor.as_ref() else {
+        return;
+    };
+    let lock = gives_power(env, balance_id, balance, &token).then_some(VotingLock {
+        amount: balance.amount,
+        unlock: balance.time_bound.timestamp,
+    });
+    update_lock(env, depositor, balance_id, lock);
+}
+
+// Bakiyeyi, varsa sahibinin oy kilitlerinden çıkarır. Bakiye kapanmadan önce çağrılmalıdır.
+pub(crate) fn remove_lock(env: &Env, balance_id: u64) {
+    if read_voting_token(env).is_none() {
+        return;
+    }
+    if let Some(depositor) = read_balance(env, balance_id).and_then(|balance| balance.depositor) {
+        update_lock(env, &depositor, balance_id, None);
+    }
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // Oy gücü veren token'ı belirler. Sadece yönetici çağırabilir ve geçmiş oy gücü
+    // kayıtları tutarlı kalsın diye sadece bir kez ayarlanabilir. Bu andan önce yatırılan
+    // kilitler, sonradan değiştirilene kadar oy gücü vermez.
+    pub fn set_voting_token(env: Env, token: Address) {
+        admin::require_admin(&env);
+        if read_voting_token(&env).is_some() {
+            panic!("voting token is already set"); // "oy token'ı zaten belirlenmiş"
+        }
+        env.storage().instance().set(&DataKey::VotingToken, &token);
+    }
+
+    // Oy gücü veren token'ı döndürür.
+    pub fn voting_token(env: Env) -> Option<Address> {
+        read_voting_token(&env)
+    }
+
+    // `owner`'ın `timestamp` anındaki oy gücünü döndürür. Geçmiş anlar için o anda geçerli kontrol
+    // noktası kullanılır; gelecek anlar için bugünkü kilitlerin o andaki gücü döner.
+    pub fn voting_power(env: Env, owner: Address, timestamp: u64) -> i128 {
+        let Some(index) = checkpoint_at(&env, &owner, timestamp) else {
+            return 0;
+        };
+        let mut checkpoint = read_checkpoint(&env, &owner, index);
+        // Önceki kontrol noktaları arasındaki eğim değişiklikleri zaten kendi kontrol noktalarına
+        // işlenmiştir; sadece sonuncudan sonrakiler ayrıca uygulanır.
+        if index + 1 == checkpoint_count(&env, &owner) {
+            for time in read_slope_times(&env, &owner) {
+                if time > timestamp {
+                    break;
+                }
+                decay(&mut checkpoint, time);
+                checkpoint.slope += read_slope_change(&env, &owner, time);
+            }
+        }
+        decay(&mut checkpoint, timestamp);
+        checkpoint.bias / MAX_LOCK_TIME as i128
+    }
+}
@@ -0,0 +1,213 @@
+// İmzalı kuponlarla (voucher) talep edilen bakiyeler.
+//
+// Yatıran, yatırma sırasında bir ed25519 açık anahtarı kaydeder. Talepçiler zincire önceden
+// yazılmaz; yatıran daha sonra zincir dışında, belirli bir adrese belirli bir miktarı talep
+// etme yetkisi veren kuponlar imzalar. Her kuponun tek kullanımlık bir numarası (nonce) vardır.
+//
+// İmzalanan mesaj: `xdr((kontrat_adresi, bakiye_id, talepçi, miktar, nonce))`.
+// Kontrat adresi ve bakiye kimliği, kuponun başka bir kontratta veya bakiyede kullanılmasını önler.
+use soroban_sdk::{
+    contractimpl, contracttype, panic_with_error, token, xdr::ToXdr, Address, Bytes, BytesN, Env,
+};
+
+use crate::{
+    check_time_bound, events, fee, is_expired, next_balance_id, pause, receive_tokens, registry,
+    ClaimableBalanceContract, ClaimableBalanceContractArgs, ClaimableBalanceContractClient,
+    DataKey, Error, TimeBound, TimeBoundKind, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VoucherBalance {
+    // Bakiyenin token adresi.
+    pub token: Address,
+    // Henüz talep edilmemiş token miktarı.
+    pub remaining: i128,
+    // Kuponları imzalayan ed25519 açık anahtarı.
+    pub signer: BytesN<32>,
+    // Bakiyenin ne zaman talep edilebileceğini belirleyen zaman sınırı.
+    pub time_bound: TimeBound,
+    // Token'ları yatıran adres; süre dolunca kalanı geri alabilir.
+    pub depositor: Address,
+}
+
+// Bir kupon için imzalanması gereken mesajı oluşturur.
+pub(crate) fn voucher_message(
+    env: &Env,
+    balance_id: u64,
+    claimant: &Address,
+    amount: i128,
+    nonce: u64,
+) -> Bytes {
+    (
+        env.current_contract_address(),
+        balance_id,
+        claimant.clone(),
+        amount,
+        nonce,
+    )
+        .to_xdr(env)
+}
+
+fn read_voucher_balance(env: &Env, balance_id: u64) -> VoucherBalance {
+    let key = DataKey::VoucherBalance(balance_id);
+    let balance = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, Error::BalanceNotFound));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    balance
+}
+
+fn write_voucher_balance(env: &Env, balance_id: u64, balance: &VoucherBalance) {
+    let key = DataKey::VoucherBalance(balance_id);
+    env.storage().persistent().set(&key, balance);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+#[contractimpl]
+impl ClaimableBalanceContract {
+    // `from`'dan `amount` kadar token yatırarak, `signer` anahtarıyla imzalanan kuponlarla
+    // talep edilecek bir bakiye oluşturur ve kimliğini döndürür.
+    // Zaman sınırı 'Before' türünde olmalıdır; aksi halde kullanılmayan kuponların karşılığı
+    // hiçbir zaman geri alınamazdı.
+    pub fn deposit_voucher(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        signer: BytesN<32>,
+        time_bound: TimeBound,
+    ) -> u64 {
+        pause::ensure_not_paused(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if time_bound.kind != TimeBoundKind::Before {
+            panic_with_error!(&env, Error::InvalidTimeBound);
+        }
+        from.require_auth();
+        registry::ensure_allowed(&env, &from);
+
+        let received = receive_tokens(&env, &token, |client| {
+            client.transfer(&from, &env.current_contract_address(), &amount)
+        });
+        let balance_id = next_balance_id(&env);
+        let (amount, fee) = fee::charge_deposit_fee(&env, &token, received);
+        events::deposit(&env, &from, balance_id, &token, amount, fee);
+        write_voucher_balance(
+            &env,
+            balance_id,
+            &VoucherBalance {
+                token,
+                remaining: amount,
+                signer,
+                time_bound,
+                depositor: from,
+            },
+        );
+        balance_id
+    }
+
+    // Yatıranın imzaladığı kuponla `claimant`'a `amount` kadar token öder. Talep ücreti kesilir.
+    // İmza geçersizse ana makine (host) çağrıyı bir kripto hatasıyla durdurur.
+    pub fn claim_with_voucher(
+        env: Env,
+        balance_id: u64,
+        claimant: Address,
+        amount: i128,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) {
+        pause::ensure_not_paused(&env);
+        claimant.require_auth();
+        registry::ensure_allowed(&env, &claimant);
+        let mut balance = read_voucher_balance(&env, balance_id);
+
+        if !check_time_bound(&env, &balance.time_bound) {
+            panic!("time predicate is not fulfilled"); // "zaman koşulu karşılanmadı"
+        }
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        let nonce_key = DataKey::VoucherNonce(balance_id, nonce);
+        if env.storage().persistent().has(&nonce_key) {
+            panic_with_error!(&env, Error::NonceUsed);
+        }
+        let message = voucher_message(&env, balance_id, &claimant, amount, nonce);
+        env.crypto()
+            .ed25519_verify(&balance.signer, &message, &signature);
+        if amount > balance.remaining {
+            panic_with_error!(&env, Error::AmountExceedsBalance);
+        }
+
+        // Kullanılan numara, bakiye yaşadığı sürece tekrar kullanılamaması için saklanır.
+        env.storage().persistent().set(&nonce_key, &true);
+        env.storage().persistent().extend_ttl(
+            &nonce_key,
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        );
+        balance.remaining -= amount;
+        write_voucher_balance(&env, balance_id, &balance);
+
+        let (paid, fee) = fee::charge_claim_fee(&env, &balance.token, amount);
+        token::Client::new(&env, &balance.token).transfer(
+            &env.current_contract_address(),
+            &claimant,
+            &paid,
+        );
+        events::claim(&env, &claimant, balance_id, &balance.token, paid, fee);
+    }
+
+    // Verilen numaralı kuponun bu bakiyede kullanılıp kullanılmadığını döndürür.
+    pub fn is_nonce_used(env: Env, balance_id: u64, nonce: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::VoucherNonce(balance_id, nonce))
+    }
+
+    // Verilen kimlikteki kuponlu bakiyeyi döndürür; yoksa `None` döner.
+    pub fn get_voucher_balance(env: Env, balance_id: u64) -> Option<VoucherBalance> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VoucherBalance(balance_id))
+    }
+
+    // Zaman sınırı dolduktan sonra talep edilmemiş kalanı yatırana geri öder.
+    // `reclaim` ile aynı kurallar geçerlidir.
+    pub fn reclaim_voucher_balance(env: Env, balance_id: u64) {
+        if !pause::read_policy(&env).allow_reclaim {
+            pause::ensure_not_paused(&env);
+        }
+        let balance = read_voucher_balance(&env, balance_id);
+        balance.depositor.require_auth();
+        registry::ensure_allowed(&env, &balance.depositor);
+        if !is_expired(&env, &balance.time_bound) {
+            panic!("balance can still be claimed"); // "bakiye hâlâ talep edilebilir"
+        }
+
+        if balance.remaining > 0 {
+            token::Client::new(&env, &balance.token).transfer(
+                &env.current_contract_address(),
+                &balance.depositor,
+                &balance.remaining,
+            );
+        }
+        events::reclaim(
+            &env,
+            &balance.depositor,
+            balance_id,
+            &balance.token,
+            balance.remaining,
+        );
+        env.storage()
+            .persistent()
+            .remove(&DataKey::VoucherBalance(balance_id));
+    }
+}
@@ -0,0 +1,187 @@
+#![no_std]
+// Her kilit için ayrı bir zaman kilitli kontrat (`ClaimableBalanceContract`) deploy eden fabrika.
+//
+// Fabrika, yöneticinin yüklediği zaman kilitli kontrat Wasm'ından yeni bir örnek deploy eder,
+// aynı çağrıda örneğe yatırmayı yapar ve deploy edilen tüm örneklerin kaydını tutar. Böylece
+// her kilidin fonları ayrı bir kontratta tutulurken bütün kilitler tek yerden listelenebilir.
+//
+// Örneklerin adresleri, sıra numaralarından türetilen sabit tuzlarla (salt) belirlenir;
+// `deployed_address` ile bir sonraki kilidin adresi önceden hesaplanabilir.
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, Address, BytesN, Env, Vec,
+};
+
+// Zaman kilitli kontratın zaman sınırı türleri. Kontrattakiyle aynı biçimde serileştirilir.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TimeBoundKind {
+    Before,
+    After,
+}
+
+// Zaman kilitli kontratın zaman sınırı. Kontrattakiyle aynı biçimde serileştirilir.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TimeBound {
+    pub kind: TimeBoundKind,
+    pub timestamp: u64,
+}
+
+// Fabrikanın deploy edilen örneklerde çağırdığı zaman kilitli kontrat fonksiyonu.
+#[contractclient(name = "TimelockClient")]
+pub trait Timelock {
+    fn deposit(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        claimants: Vec<Address>,
+        time_bound: TimeBound,
+    ) -> u64;
+}
+
+// Fabrika tarafından deploy edilen bir kilit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DeployedLock {
+    // Kilidin zaman kilitli kontrat örneği.
+    pub contract: Address,
+    // Örnekteki bakiyenin kimliği.
+    pub balance_id: u64,
+    // Kilidi oluşturan yatıran.
+    pub depositor: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    // Fabrikanın ve deploy edilen örneklerin yöneticisi.
+    Admin,
+    // Deploy edilecek zaman kilitli kontrat Wasm'ının özeti.
+    WasmHash,
+    // Deploy edilen kilit sayısı.
+    LockCount,
+    // Verilen sıradaki kilit.
+    Lock(u32),
+}
+
+// Bir sayfada döndürülebilecek en fazla kilit sayısı.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+// Kayıtların ömrü, zaman kilitli kontrattaki bakiyelerle aynıdır.
+const DAY_IN_LEDGERS: u32 = 17280;
+const LOCK_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const LOCK_LIFETIME_THRESHOLD: u32 = LOCK_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+fn lock_count(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::LockCount).unwrap_or(0)
+}
+
+fn read_admin(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+// `index` sıradaki kilidin deploy tuzu: sıra numarası, büyük uçlu (big-endian) olarak sona yazılır.
+fn lock_salt(env: &Env, index: u32) -> BytesN<32> {
+    let mut salt = [0; 32];
+    salt[28..].copy_from_slice(&index.to_be_bytes());
+    BytesN::from_array(env, &salt)
+}
+
+#[contract]
+pub struct TimelockFactory;
+
+#[contractimpl]
+impl TimelockFactory {
+    // `admin`: Fabrikanın ve deploy edilen örneklerin yöneticisi.
+    // `wasm_hash`: Önceden yüklenmiş zaman kilitli kontrat Wasm'ının özeti.
+    pub fn __constructor(env: Env, admin: Address, wasm_hash: BytesN<32>) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+    }
+
+    // Bundan sonra deploy edilecek örneklerin Wasm'ını değiştirir. Sadece yönetici çağırabilir.
+    // Önceden deploy edilmiş örnekler etkilenmez; onlar kendi `upgrade` fonksiyonlarıyla yükseltilir.
+    pub fn set_wasm_hash(env: Env, wasm_hash: BytesN<32>) {
+        read_admin(&env).require_auth();
+        env.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+    }
+
+    // Yeni bir zaman kilitli kontrat örneği deploy eder ve aynı çağrıda `from`'dan `amount`
+    // kadar token'ı `deposit` ile örneğe yatırır. Parametreler `deposit` ile aynıdır.
+    // `from`, hem bu çağrıyı hem de örnekteki yatırmayı ve token transferini yetkilendirmelidir.
+    pub fn deploy_lock(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        claimants: Vec<Address>,
+        time_bound: TimeBound,
+    ) -> DeployedLock {
+        from.require_auth();
+        let index = lock_count(&env);
+        let wasm_hash: BytesN<32> = env.storage().instance().get(&DataKey::WasmHash).unwrap();
+        let contract = env
+            .deployer()
+            .with_current_contract(lock_salt(&env, index))
+            .deploy_v2(wasm_hash, (read_admin(&env),));
+        let balance_id = TimelockClient::new(&env, &contract).deposit(
+            &from,
+            &token,
+            &amount,
+            &claimants,
+            &time_bound,
+        );
+
+        let lock = DeployedLock {
+            contract,
+            balance_id,
+            depositor: from,
+        };
+        let key = DataKey::Lock(index);
+        env.storage().persistent().set(&key, &lock);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LOCK_LIFETIME_THRESHOLD, LOCK_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .set(&DataKey::LockCount, &(index + 1));
+        lock
+    }
+
+    // `index` sıradaki kilidin deploy edileceği (veya edildiği) adresi döndürür.
+    pub fn deployed_address(env: Env, index: u32) -> Address {
+        env.deployer()
+            .with_current_contract(lock_salt(&env, index))
+            .deployed_address()
+    }
+
+    // Deploy edilen kilit sayısını döndürür.
+    pub fn lock_count(env: Env) -> u32 {
+        lock_count(&env)
+    }
+
+    // `index` sıradaki kilidi döndürür; yoksa `None` döner.
+    pub fn get_lock(env: Env, index: u32) -> Option<DeployedLock> {
+        env.storage().persistent().get(&DataKey::Lock(index))
+    }
+
+    // `start` sırasından başlayarak en fazla `limit` (ve `MAX_PAGE_SIZE`) kilidi sırayla döndürür.
+    pub fn locks(env: Env, start: u32, limit: u32) -> Vec<DeployedLock> {
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(lock_count(&env));
+        let mut locks = Vec::new(&env);
+        for index in start..end {
+            locks.push_back(
+                env.storage()
+                    .persistent()
+                    .get(&DataKey::Lock(index))
+                    .unwrap(),
+            );
+        }
+        locks
+    }
+}
+
+mod test;
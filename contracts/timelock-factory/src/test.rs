@@ -0,0 +1,164 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, vec};
+
+// Zaman kilitli kontratın Wasm'ı ve testlerin örneklerde çağırdığı fonksiyonları.
+mod timelock {
+    use soroban_sdk::{contractclient, Address, Env};
+
+    // Wasm, `wasm-fixtures` ile kontratın güncel kaynağından derlenir.
+    pub fn wasm() -> &'static [u8] {
+        wasm_fixtures::wasm("soroban-timelock")
+    }
+
+    // Trait'in kendisi kullanılmaz; sadece ondan üretilen `Client` kullanılır.
+    #[allow(dead_code)]
+    #[contractclient(name = "Client")]
+    pub trait Timelock {
+        fn set_max_claimants(env: Env, max_claimants: u32);
+        fn max_claimants(env: Env) -> u32;
+        fn claim(env: Env, balance_id: u64, claimant: Address);
+    }
+}
+
+struct FactoryTest<'a> {
+    env: Env,
+    admin: Address,
+    depositor: Address,
+    claimant: Address,
+    token: token::Client<'a>,
+    factory: TimelockFactoryClient<'a>,
+}
+
+impl FactoryTest<'_> {
+    // Zaman kilitli kontrat Wasm'ını yükler, fabrikayı deploy eder ve yatırana 1000 token basar.
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(12345);
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let claimant = Address::generate(&env);
+        let sac = env.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&env, &sac.address()).mint(&depositor, &1000);
+
+        let wasm_hash = env.deployer().upload_contract_wasm(timelock::wasm());
+        let factory = TimelockFactoryClient::new(
+            &env,
+            &env.register(TimelockFactory, (admin.clone(), wasm_hash)),
+        );
+        FactoryTest {
+            token: token::Client::new(&env, &sac.address()),
+            env,
+            admin,
+            depositor,
+            claimant,
+            factory,
+        }
+    }
+
+    fn deploy(&self, amount: i128, timestamp: u64) -> DeployedLock {
+        self.factory.deploy_lock(
+            &self.depositor,
+            &self.token.address,
+            &amount,
+            &vec![&self.env, self.claimant.clone()],
+            &TimeBound {
+                kind: TimeBoundKind::After,
+                timestamp,
+            },
+        )
+    }
+}
+
+// Bu test, fabrikanın her kilit için yatırması yapılmış ayrı bir örnek deploy ettiğini ve
+// kilidin örnekten talep edilebildiğini kontrol eder.
+#[test]
+fn test_deploy_lock() {
+    let test = FactoryTest::setup();
+    let lock = test.deploy(400, 12346);
+
+    assert_eq!(lock.depositor, test.depositor);
+    assert_eq!(test.token.balance(&lock.contract), 400);
+    assert_eq!(test.token.balance(&test.depositor), 600);
+
+    let instance = timelock::Client::new(&test.env, &lock.contract);
+    // Örneğin yöneticisi fabrikanın yöneticisidir.
+    instance.set_max_claimants(&5);
+    assert_eq!(instance.max_claimants(), 5);
+
+    test.env.ledger().set_timestamp(12346);
+    instance.claim(&lock.balance_id, &test.claimant);
+    assert_eq!(test.token.balance(&test.claimant), 400);
+}
+
+// Bu test, örneklerin adreslerinin önceden hesaplanabildiğini ve tüm kilitlerin
+// kayıttan sırayla listelenebildiğini kontrol eder.
+#[test]
+fn test_registry_and_deterministic_addresses() {
+    let test = FactoryTest::setup();
+    let predicted = [
+        test.factory.deployed_address(&0),
+        test.factory.deployed_address(&1),
+        test.factory.deployed_address(&2),
+    ];
+    assert_ne!(predicted[0], predicted[1]);
+
+    let locks = [
+        test.deploy(100, 12346),
+        test.deploy(200, 12347),
+        test.deploy(300, 12348),
+    ];
+    assert_eq!(test.factory.lock_count(), 3);
+    for (index, lock) in locks.iter().enumerate() {
+        assert_eq!(lock.contract, predicted[index]);
+        assert_eq!(test.factory.get_lock(&(index as u32)), Some(lock.clone()));
+    }
+    assert_eq!(test.factory.get_lock(&3), None);
+
+    assert_eq!(
+        test.factory.locks(&1, &10),
+        vec![&test.env, locks[1].clone(), locks[2].clone()]
+    );
+    assert_eq!(
+        test.factory.locks(&0, &1),
+        vec![&test.env, locks[0].clone()]
+    );
+    assert_eq!(test.factory.locks(&5, &10).len(), 0);
+}
+
+// Bu test, geçersiz bir yatırmada örneğin de deploy edilmediğini ve kaydın değişmediğini kontrol eder.
+#[test]
+fn test_invalid_deposit_reverts_deployment() {
+    let test = FactoryTest::setup();
+    assert!(test
+        .factory
+        .try_deploy_lock(
+            &test.depositor,
+            &test.token.address,
+            &0,
+            &vec![&test.env, test.claimant.clone()],
+            &TimeBound {
+                kind: TimeBoundKind::After,
+                timestamp: 12346,
+            },
+        )
+        .is_err());
+    assert_eq!(test.factory.lock_count(), 0);
+
+    // Aynı tuz yeniden kullanılabilir.
+    let lock = test.deploy(100, 12346);
+    assert_eq!(lock.contract, test.factory.deployed_address(&0));
+}
+
+// Bu test, Wasm özetini sadece yöneticinin değiştirebildiğini kontrol eder.
+#[test]
+fn test_set_wasm_hash_requires_admin() {
+    let test = FactoryTest::setup();
+    let wasm_hash = test.env.deployer().upload_contract_wasm(timelock::wasm());
+    test.factory.set_wasm_hash(&wasm_hash);
+    assert_eq!(test.env.auths()[0].0, test.admin);
+}
@@ -0,0 +1,51 @@
+// Kontrat testlerinin deploy ettiği Wasm'ları derleyen test yardımcısı.
+//
+// Bir kontratın testleri başka bir kontratı (veya kendisini) Wasm olarak deploy etmesi
+// gerektiğinde, Wasm depoya derlenmiş dosya olarak eklenmez; ilk ihtiyaç duyulduğunda kontratın
+// güncel kaynağından derlenir. Derleme sadece bu Wasm'ı kullanan testler çalışırken yapılır;
+// `cargo build`, `cargo check` ve clippy etkilenmez. `wasm32v1-none` hedefinin kurulu olması
+// gerekir (`rustup target add wasm32v1-none`).
+use std::{env, fs, path::PathBuf, process::Command, sync::Mutex};
+
+// Wasm'ların derlendiği hedef.
+const TARGET: &str = "wasm32v1-none";
+
+// Bu süreçte derlenmiş Wasm'lar; her paket bir kez derlenir.
+static BUILT: Mutex<Vec<(&'static str, &'static [u8])>> = Mutex::new(Vec::new());
+
+// Çalışma alanındaki `package` kontratını Wasm olarak derler ve baytlarını döndürür.
+// Wasm, testi çalıştıran derlemenin kilidine takılmamak için ayrı bir hedef dizininde
+// (`target/wasm-fixtures`) derlenir.
+pub fn wasm(package: &'static str) -> &'static [u8] {
+    let mut built = BUILT.lock().unwrap();
+    if let Some((_, wasm)) = built.iter().find(|(name, _)| *name == package) {
+        return wasm;
+    }
+
+    let workspace_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let target_dir = env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_dir.join("target"))
+        .join("wasm-fixtures");
+    // Derleme çıktısı test çıktısına karışmasın diye sadece hata durumunda gösterilir.
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .current_dir(&workspace_dir)
+        .args(["build", "--release", "--target", TARGET, "-p", package])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .output()
+        .unwrap();
+    if !output.status.success() {
+        // "Wasm derlemesi başarısız; `rustup target add wasm32v1-none` ile hedef kurulmalı"
+        panic!(
+            "building {package} for {TARGET} failed; is the target installed?\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let file = format!("{}.wasm", package.replace('-', "_"));
+    let wasm = fs::read(target_dir.join(TARGET).join("release").join(file)).unwrap();
+    let wasm: &'static [u8] = Vec::leak(wasm);
+    built.push((package, wasm));
+    wasm
+}